@@ -0,0 +1,60 @@
+use crate::{
+    ast::{
+        terminal::{
+            identifier::Identifier,
+            symbol::{Dot, LBracket, RBracket},
+        },
+        trivia::with_trivia::WithTrivia,
+        utils::flags::Scope,
+    },
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::Expression;
+
+/// `object.field`, where the field may be a reserved word as in `x.class`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemberExpression<F: Scope>(
+    pub Expression<F>,
+    pub WithTrivia<Dot>,
+    pub WithTrivia<Identifier>,
+);
+impl<V: Visitor, F: Scope> Visitable<V> for MemberExpression<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for MemberExpression<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+/// `array[index]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexExpression<F: Scope>(
+    pub Expression<F>,
+    pub WithTrivia<LBracket>,
+    pub WithTrivia<Expression<F>>,
+    pub WithTrivia<RBracket>,
+);
+impl<V: Visitor, F: Scope> Visitable<V> for IndexExpression<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+        v.visit(&self.3);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for IndexExpression<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+        self.3.accept_mut(v);
+    }
+}
@@ -0,0 +1,204 @@
+use nom::{branch::alt, combinator::map};
+
+use crate::{
+    ast::{
+        terminal::symbol::{
+            AmpersandEqual, BackslashEqual, CaretEqual, Colon, Equal, LAngleLAngleEqual,
+            MinusEqual, PercentEqual, PipeEqual, PlusEqual, Question, RAngleRAngleEqual,
+            RAngleRAngleRAngleEqual, SlashEqual, StarEqual, StarStarEqual,
+        },
+        trivia::with_trivia::WithTrivia,
+        utils::flags::Scope,
+    },
+    parser::{IResult, Parser},
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::Expression;
+
+/// `condition ? then : otherwise`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TernaryExpression<F: Scope>(
+    pub Expression<F>,
+    pub WithTrivia<Question>,
+    pub WithTrivia<Expression<F>>,
+    pub WithTrivia<Colon>,
+    pub WithTrivia<Expression<F>>,
+);
+impl<V: Visitor, F: Scope> Visitable<V> for TernaryExpression<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+        v.visit(&self.3);
+        v.visit(&self.4);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for TernaryExpression<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+        self.3.accept_mut(v);
+        self.4.accept_mut(v);
+    }
+}
+
+/// `target = value` or a compound form such as `target += value`. The value
+/// is a full expression, which makes assignments right associative.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssignmentExpression<F: Scope>(
+    pub Expression<F>,
+    pub WithTrivia<AssignmentOperator>,
+    pub WithTrivia<Expression<F>>,
+);
+impl<V: Visitor, F: Scope> Visitable<V> for AssignmentExpression<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for AssignmentExpression<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssignmentOperator {
+    Assign(Equal),
+    Add(PlusEqual),
+    Sub(MinusEqual),
+    Mul(StarEqual),
+    Div(SlashEqual),
+    Mod(PercentEqual),
+    Pow(StarStarEqual),
+    IntDiv(BackslashEqual),
+    Shl(LAngleLAngleEqual),
+    Shr(RAngleRAngleEqual),
+    UShr(RAngleRAngleRAngleEqual),
+    BitAnd(AmpersandEqual),
+    BitOr(PipeEqual),
+    BitXor(CaretEqual),
+}
+impl<'a> Parser<&'a str> for AssignmentOperator {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        // Longest tokens first so that `**=` is not read as `*` followed by `*=`.
+        alt((
+            map(RAngleRAngleRAngleEqual::parse, Self::UShr),
+            map(RAngleRAngleEqual::parse, Self::Shr),
+            map(LAngleLAngleEqual::parse, Self::Shl),
+            map(StarStarEqual::parse, Self::Pow),
+            map(PlusEqual::parse, Self::Add),
+            map(MinusEqual::parse, Self::Sub),
+            map(StarEqual::parse, Self::Mul),
+            map(SlashEqual::parse, Self::Div),
+            map(PercentEqual::parse, Self::Mod),
+            map(BackslashEqual::parse, Self::IntDiv),
+            map(AmpersandEqual::parse, Self::BitAnd),
+            map(PipeEqual::parse, Self::BitOr),
+            map(CaretEqual::parse, Self::BitXor),
+            map(Equal::parse, Self::Assign),
+        ))(input)
+    }
+}
+impl<V: Visitor> Visitable<V> for AssignmentOperator {
+    default fn accept(&self, v: &mut V) {
+        match self {
+            Self::Assign(node) => v.visit(node),
+            Self::Add(node) => v.visit(node),
+            Self::Sub(node) => v.visit(node),
+            Self::Mul(node) => v.visit(node),
+            Self::Div(node) => v.visit(node),
+            Self::Mod(node) => v.visit(node),
+            Self::Pow(node) => v.visit(node),
+            Self::IntDiv(node) => v.visit(node),
+            Self::Shl(node) => v.visit(node),
+            Self::Shr(node) => v.visit(node),
+            Self::UShr(node) => v.visit(node),
+            Self::BitAnd(node) => v.visit(node),
+            Self::BitOr(node) => v.visit(node),
+            Self::BitXor(node) => v.visit(node),
+        }
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for AssignmentOperator {
+    default fn accept_mut(&mut self, v: &mut V) {
+        match self {
+            Self::Assign(node) => node.accept_mut(v),
+            Self::Add(node) => node.accept_mut(v),
+            Self::Sub(node) => node.accept_mut(v),
+            Self::Mul(node) => node.accept_mut(v),
+            Self::Div(node) => node.accept_mut(v),
+            Self::Mod(node) => node.accept_mut(v),
+            Self::Pow(node) => node.accept_mut(v),
+            Self::IntDiv(node) => node.accept_mut(v),
+            Self::Shl(node) => node.accept_mut(v),
+            Self::Shr(node) => node.accept_mut(v),
+            Self::UShr(node) => node.accept_mut(v),
+            Self::BitAnd(node) => node.accept_mut(v),
+            Self::BitOr(node) => node.accept_mut(v),
+            Self::BitXor(node) => node.accept_mut(v),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::structure::file::File,
+        error::ParseErrorKind,
+        test_utils::{error_kind, grouped, round_trip, Expression},
+    };
+
+    #[test]
+    fn ternaries() {
+        assert_eq!(grouped("a ? b : c"), "(a ? b : c)");
+        assert_eq!(grouped("a ? b : c ? d : e"), "(a ? b : (c ? d : e))");
+        assert_eq!(grouped("a ? b ? c : d : e"), "(a ? (b ? c : d) : e)");
+        assert_eq!(grouped("a || b ? c + 1 : d"), "((a || b) ? (c + 1) : d)");
+        assert_eq!(grouped("a ? b = 1 : c"), "(a ? (b = 1) : c)");
+        assert_eq!(grouped("x = a ? b : c"), "(x = (a ? b : c))");
+        assert_eq!(grouped("a?b:c"), "(a ? b : c)");
+        let Expression::Ternary(ternary) = round_trip("a ? f : x -> x") else {
+            panic!()
+        };
+        assert!(matches!(ternary.2 .1, Expression::Identifier(_)));
+        assert!(matches!(ternary.4 .1, Expression::Lambda(_)));
+    }
+
+    #[test]
+    fn assignments() {
+        assert_eq!(grouped("a = b = c"), "(a = (b = c))");
+        assert_eq!(grouped("a += b * 2"), "(a += (b * 2))");
+        assert_eq!(grouped("a.b[0] **= 2"), "(a.b[0] **= 2)");
+        for op in [
+            "=", "+=", "-=", "*=", "/=", "%=", "**=", "\\=", "<<=", ">>=", ">>>=", "&=", "|=", "^=",
+        ] {
+            let src = format!("a {op} 1");
+            assert_eq!(grouped(&src), format!("({src})"));
+        }
+        assert_eq!(
+            grouped("h = i <<= j >>>= k \\= 3"),
+            "(h = (i <<= (j >>>= (k \\= 3))))"
+        );
+    }
+
+    #[test]
+    fn assignment_targets() {
+        for src in ["1 = 2", "a + b = c", "f() = 1", "a ? b : c = d", "-a += 1"] {
+            assert_eq!(
+                error_kind::<Expression>(src),
+                ParseErrorKind::InvalidAssignmentTarget,
+                "{src}"
+            );
+        }
+        assert_eq!(
+            error_kind::<File>("var x = 1\n(a) = 2"),
+            ParseErrorKind::InvalidAssignmentTarget
+        );
+    }
+}
@@ -0,0 +1,325 @@
+use nom::{
+    branch::alt,
+    combinator::{map, opt},
+    sequence::pair,
+};
+
+use crate::{
+    ast::{
+        terminal::{
+            keyword::{KwAnd, KwIn, KwInstanceof, KwOr, KwXor},
+            symbol::{
+                Ampersand, AmpersandAmpersand, Backslash, Caret, EqualEqual, EqualEqualEqual,
+                ExclamationEqual, ExclamationEqualEqual, LAngle, LAngleEqual, LAngleLAngle, Minus,
+                Percent, Pipe, PipePipe, Plus, RAngle, RAngleEqual, RAngleRAngle,
+                RAngleRAngleRAngle, Slash, Star, StarStar,
+            },
+        },
+        trivia::with_trivia::{with_trivia, WithTrivia},
+        utils::flags::Scope,
+    },
+    parser::{version, IResult, Parser, Version},
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::{cast::CastExpression, Expression};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BinaryExpression<F: Scope>(
+    pub Expression<F>,
+    pub WithTrivia<BinaryOperator>,
+    pub WithTrivia<Expression<F>>,
+);
+
+impl<F: Scope> BinaryExpression<F> {
+    /// Parses a chain of binary operators whose precedence is at least
+    /// `min_precedence`, using `operand` for the terms between them.
+    pub fn parse_with<'a, P>(
+        input: &'a str,
+        min_precedence: u8,
+        operand: &mut P,
+    ) -> IResult<&'a str, Expression<F>>
+    where
+        P: FnMut(&'a str) -> IResult<&'a str, Expression<F>>,
+    {
+        let (mut input, mut lhs) = operand(input)?;
+        loop {
+            if version() >= Version::V4 && BinaryOperator::RELATIONAL_PRECEDENCE >= min_precedence {
+                if let (rest, Some((kw_as, ty))) = opt(pair(
+                    <_ as Parser<&'a str>>::parse,
+                    <_ as Parser<&'a str>>::parse,
+                ))(input)?
+                {
+                    lhs = Expression::Cast(Box::new(CastExpression(lhs, kw_as, ty)));
+                    input = rest;
+                    continue;
+                }
+            }
+            let (rest, op) = match WithTrivia::<BinaryOperator>::parse(input) {
+                Ok(res) => res,
+                Err(nom::Err::Error(_)) => break,
+                Err(e) => return Err(e),
+            };
+            let precedence = op.1.precedence();
+            if precedence < min_precedence {
+                break;
+            }
+            let next = if op.1.is_right_associative() {
+                precedence
+            } else {
+                precedence + 1
+            };
+            // An operator without a right-hand side is left for the caller,
+            // e.g. the `+` of a `+=` compound assignment.
+            let (rest, rhs) = match with_trivia(|i| Self::parse_with(i, next, operand))(rest) {
+                Ok(res) => res,
+                Err(nom::Err::Error(_)) => break,
+                Err(e) => return Err(e),
+            };
+            lhs = Expression::Binary(Box::new(Self(lhs, op, rhs)));
+            input = rest;
+        }
+        Ok((input, lhs))
+    }
+}
+
+impl<V: Visitor, F: Scope> Visitable<V> for BinaryExpression<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for BinaryExpression<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BinaryOperator {
+    // Arithmetic
+    Add(Plus),
+    Sub(Minus),
+    Mul(Star),
+    Div(Slash),
+    Mod(Percent),
+    IntDiv(Backslash),
+    Pow(StarStar),
+    // Bitwise
+    Shl(LAngleLAngle),
+    Shr(RAngleRAngle),
+    UShr(RAngleRAngleRAngle),
+    BitAnd(Ampersand),
+    BitOr(Pipe),
+    BitXor(Caret),
+    // Comparison
+    Eq(EqualEqual),
+    Ne(ExclamationEqual),
+    StrictEq(EqualEqualEqual),
+    StrictNe(ExclamationEqualEqual),
+    Lt(LAngle),
+    Le(LAngleEqual),
+    Gt(RAngle),
+    Ge(RAngleEqual),
+    Instanceof(KwInstanceof),
+    In(KwIn),
+    // Logical
+    And(AmpersandAmpersand),
+    Or(PipePipe),
+    AndKw(KwAnd),
+    OrKw(KwOr),
+    Xor(KwXor),
+}
+
+impl BinaryOperator {
+    /// Precedence of the shift operators, the loosest level whose operands
+    /// cannot contain a bare `>`.
+    pub const SHIFT_PRECEDENCE: u8 = 9;
+
+    /// Precedence of the comparisons, `instanceof`, `in` and `as` casts.
+    pub const RELATIONAL_PRECEDENCE: u8 = 8;
+
+    /// Binding power of the operator, higher binds tighter.
+    pub fn precedence(&self) -> u8 {
+        match self {
+            Self::Or(_) | Self::OrKw(_) => 1,
+            Self::Xor(_) => 2,
+            Self::And(_) | Self::AndKw(_) => 3,
+            Self::BitOr(_) => 4,
+            Self::BitXor(_) => 5,
+            Self::BitAnd(_) => 6,
+            Self::Eq(_) | Self::Ne(_) | Self::StrictEq(_) | Self::StrictNe(_) => 7,
+            Self::Lt(_)
+            | Self::Le(_)
+            | Self::Gt(_)
+            | Self::Ge(_)
+            | Self::Instanceof(_)
+            | Self::In(_) => Self::RELATIONAL_PRECEDENCE,
+            Self::Shl(_) | Self::Shr(_) | Self::UShr(_) => Self::SHIFT_PRECEDENCE,
+            Self::Add(_) | Self::Sub(_) => 10,
+            Self::Mul(_) | Self::Div(_) | Self::Mod(_) | Self::IntDiv(_) => 11,
+            Self::Pow(_) => 12,
+        }
+    }
+
+    pub fn is_right_associative(&self) -> bool {
+        matches!(self, Self::Pow(_))
+    }
+}
+
+impl<'a> Parser<&'a str> for BinaryOperator {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        // Longest tokens first so that `>>>` is not read as `>` `>` `>`.
+        alt((
+            alt((
+                map(StarStar::parse, Self::Pow),
+                map(Plus::parse, Self::Add),
+                map(Minus::parse, Self::Sub),
+                map(Star::parse, Self::Mul),
+                map(Slash::parse, Self::Div),
+                map(Percent::parse, Self::Mod),
+                map(Backslash::parse, Self::IntDiv),
+            )),
+            alt((
+                map(LAngleLAngle::parse, Self::Shl),
+                map(RAngleRAngleRAngle::parse, Self::UShr),
+                map(RAngleRAngle::parse, Self::Shr),
+                map(AmpersandAmpersand::parse, Self::And),
+                map(PipePipe::parse, Self::Or),
+                map(Ampersand::parse, Self::BitAnd),
+                map(Pipe::parse, Self::BitOr),
+                map(Caret::parse, Self::BitXor),
+            )),
+            alt((
+                map(EqualEqualEqual::parse, Self::StrictEq),
+                map(EqualEqual::parse, Self::Eq),
+                map(ExclamationEqualEqual::parse, Self::StrictNe),
+                map(ExclamationEqual::parse, Self::Ne),
+                map(LAngleEqual::parse, Self::Le),
+                map(LAngle::parse, Self::Lt),
+                map(RAngleEqual::parse, Self::Ge),
+                map(RAngle::parse, Self::Gt),
+            )),
+            alt((
+                map(KwAnd::parse, Self::AndKw),
+                map(KwOr::parse, Self::OrKw),
+                map(KwXor::parse, Self::Xor),
+                map(KwInstanceof::parse, Self::Instanceof),
+                map(KwIn::parse, Self::In),
+            )),
+        ))(input)
+    }
+}
+
+impl<V: Visitor> Visitable<V> for BinaryOperator {
+    default fn accept(&self, v: &mut V) {
+        match self {
+            Self::Add(node) => v.visit(node),
+            Self::Sub(node) => v.visit(node),
+            Self::Mul(node) => v.visit(node),
+            Self::Div(node) => v.visit(node),
+            Self::Mod(node) => v.visit(node),
+            Self::IntDiv(node) => v.visit(node),
+            Self::Pow(node) => v.visit(node),
+            Self::Shl(node) => v.visit(node),
+            Self::Shr(node) => v.visit(node),
+            Self::UShr(node) => v.visit(node),
+            Self::BitAnd(node) => v.visit(node),
+            Self::BitOr(node) => v.visit(node),
+            Self::BitXor(node) => v.visit(node),
+            Self::Eq(node) => v.visit(node),
+            Self::Ne(node) => v.visit(node),
+            Self::StrictEq(node) => v.visit(node),
+            Self::StrictNe(node) => v.visit(node),
+            Self::Lt(node) => v.visit(node),
+            Self::Le(node) => v.visit(node),
+            Self::Gt(node) => v.visit(node),
+            Self::Ge(node) => v.visit(node),
+            Self::Instanceof(node) => v.visit(node),
+            Self::In(node) => v.visit(node),
+            Self::And(node) => v.visit(node),
+            Self::Or(node) => v.visit(node),
+            Self::AndKw(node) => v.visit(node),
+            Self::OrKw(node) => v.visit(node),
+            Self::Xor(node) => v.visit(node),
+        }
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for BinaryOperator {
+    default fn accept_mut(&mut self, v: &mut V) {
+        match self {
+            Self::Add(node) => node.accept_mut(v),
+            Self::Sub(node) => node.accept_mut(v),
+            Self::Mul(node) => node.accept_mut(v),
+            Self::Div(node) => node.accept_mut(v),
+            Self::Mod(node) => node.accept_mut(v),
+            Self::IntDiv(node) => node.accept_mut(v),
+            Self::Pow(node) => node.accept_mut(v),
+            Self::Shl(node) => node.accept_mut(v),
+            Self::Shr(node) => node.accept_mut(v),
+            Self::UShr(node) => node.accept_mut(v),
+            Self::BitAnd(node) => node.accept_mut(v),
+            Self::BitOr(node) => node.accept_mut(v),
+            Self::BitXor(node) => node.accept_mut(v),
+            Self::Eq(node) => node.accept_mut(v),
+            Self::Ne(node) => node.accept_mut(v),
+            Self::StrictEq(node) => node.accept_mut(v),
+            Self::StrictNe(node) => node.accept_mut(v),
+            Self::Lt(node) => node.accept_mut(v),
+            Self::Le(node) => node.accept_mut(v),
+            Self::Gt(node) => node.accept_mut(v),
+            Self::Ge(node) => node.accept_mut(v),
+            Self::Instanceof(node) => node.accept_mut(v),
+            Self::In(node) => node.accept_mut(v),
+            Self::And(node) => node.accept_mut(v),
+            Self::Or(node) => node.accept_mut(v),
+            Self::AndKw(node) => node.accept_mut(v),
+            Self::OrKw(node) => node.accept_mut(v),
+            Self::Xor(node) => node.accept_mut(v),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::grouped;
+
+    #[test]
+    fn precedence() {
+        assert_eq!(grouped("a + b * c"), "(a + (b * c))");
+        assert_eq!(grouped("a * b + c"), "((a * b) + c)");
+        assert_eq!(grouped("a ** b * c"), "((a ** b) * c)");
+        assert_eq!(grouped("a << b + c"), "(a << (b + c))");
+        assert_eq!(grouped("a < b == c > d"), "((a < b) == (c > d))");
+        assert_eq!(grouped("a & b ^ c | d"), "(((a & b) ^ c) | d)");
+        assert_eq!(grouped("a || b && c"), "(a || (b && c))");
+        assert_eq!(grouped("a or b xor c and d"), "(a or (b xor (c and d)))");
+        assert_eq!(grouped("a \\ b % c"), "((a \\ b) % c)");
+    }
+
+    #[test]
+    fn associativity() {
+        assert_eq!(grouped("a - b - c"), "((a - b) - c)");
+        assert_eq!(grouped("a / b / c"), "((a / b) / c)");
+        assert_eq!(grouped("a ** b ** c"), "(a ** (b ** c))");
+        assert_eq!(grouped("a >>> b >> c"), "((a >>> b) >> c)");
+    }
+
+    #[test]
+    fn longest_operator_wins() {
+        assert_eq!(grouped("a === b"), "(a === b)");
+        assert_eq!(grouped("a !== b"), "(a !== b)");
+        assert_eq!(grouped("a >= b"), "(a >= b)");
+        assert_eq!(grouped("a >>> b"), "(a >>> b)");
+    }
+
+    #[test]
+    fn writer_round_trip() {
+        assert_eq!(grouped("a   +/* c */ b*c ** d"), "(a + (b * (c ** d)))");
+        assert_eq!(grouped("a\n  && b\n  || c"), "((a && b) || c)");
+        assert_eq!(grouped("a // c\n - b"), "(a - b)");
+    }
+}
@@ -0,0 +1,186 @@
+use nom::{
+    combinator::{map, opt},
+    sequence::{pair, tuple},
+};
+
+use crate::{
+    ast::{
+        terminal::{
+            identifier::Identifier,
+            keyword::KwNew,
+            symbol::{Comma, LParen, RParen},
+        },
+        trivia::with_trivia::{with_trivia, WithTrivia},
+        utils::{flags::Scope, separated::Separated0},
+    },
+    parser::{IResult, Parser},
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::{access::MemberExpression, Expression};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallExpression<F: Scope>(pub Expression<F>, pub WithTrivia<Arguments<F>>);
+impl<V: Visitor, F: Scope> Visitable<V> for CallExpression<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for CallExpression<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Arguments<F: Scope>(
+    pub LParen,
+    pub Separated0<WithTrivia<Expression<F>>, WithTrivia<Comma>>,
+    pub WithTrivia<RParen>,
+);
+impl<'a, F: Scope> Parser<&'a str> for Arguments<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(lparen, args, rparen)| Self(lparen, args, rparen),
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for Arguments<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for Arguments<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+/// `new Foo(1, 2)`, or `new Foo` without arguments.
+///
+/// The class is a primary expression followed by member accesses only, so
+/// that the argument list belongs to `new` and `new Foo().bar` reads the
+/// `bar` of the new object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewExpression<F: Scope>(
+    pub KwNew,
+    pub WithTrivia<Expression<F>>,
+    pub Option<WithTrivia<Arguments<F>>>,
+);
+impl<F: Scope> NewExpression<F> {
+    fn parse_class(input: &str) -> IResult<&str, Expression<F>> {
+        let (mut input, mut node) = Expression::parse_primary(input)?;
+        while let (rest, Some((dot, name))) = opt(pair(
+            <_ as Parser<&str>>::parse,
+            with_trivia(Identifier::parse_name),
+        ))(input)?
+        {
+            node = Expression::Member(Box::new(MemberExpression(node, dot, name)));
+            input = rest;
+        }
+        Ok((input, node))
+    }
+}
+impl<'a, F: Scope> Parser<&'a str> for NewExpression<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                with_trivia(Self::parse_class),
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(new, class, arguments)| Self(new, class, arguments),
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for NewExpression<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for NewExpression<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::ParseErrorKind,
+        test_utils::{error_kind, grouped, rejects, round_trip, Expression},
+    };
+
+    #[test]
+    fn postfix_chains() {
+        assert_eq!(
+            grouped("getWeapons(getEntity())[0].name"),
+            "getWeapons(getEntity())[0].name"
+        );
+        assert_eq!(grouped("f(a, b + 1)(c)"), "f(a, (b + 1))(c)");
+        assert_eq!(grouped("a.b.c()"), "a.b.c()");
+        assert_eq!(grouped("x.class"), "x.class");
+        assert_eq!(grouped("m[a][b + 1]"), "m[a][(b + 1)]");
+        assert_eq!(grouped("f().a++"), "(f().a++)");
+    }
+
+    #[test]
+    fn calls_cannot_be_incremented() {
+        assert_eq!(
+            error_kind::<Expression>("f()++"),
+            ParseErrorKind::InvalidAssignmentTarget
+        );
+    }
+
+    #[test]
+    fn chains_round_trip() {
+        assert_eq!(grouped("f( a , /* c */ b // x\n )"), "f(a, b)");
+        assert_eq!(grouped("a [ 1 ] . b ()"), "a[1].b()");
+        assert_eq!(grouped("a\n  .b\n  .c()"), "a.b.c()");
+        let Expression::Call(call) = round_trip("f()") else {
+            panic!()
+        };
+        assert!(call.1 .1 .1 .0.is_empty());
+    }
+
+    #[test]
+    fn arguments_have_no_trailing_comma() {
+        rejects::<Expression>("f(a,)");
+        rejects::<Expression>("f(,)");
+        rejects::<Expression>("f(a,,b)");
+    }
+
+    #[test]
+    fn new_takes_one_argument_list() {
+        assert_eq!(grouped("new Foo()"), "(new Foo())");
+        assert_eq!(grouped("new Foo(1, a + b)"), "(new Foo(1, (a + b)))");
+        assert_eq!(grouped("new Foo().bar"), "(new Foo()).bar");
+        assert_eq!(grouped("new Foo(1).bar()"), "(new Foo(1)).bar()");
+        assert_eq!(grouped("new Foo()[0]++"), "((new Foo())[0]++)");
+        assert_eq!(grouped("new Foo()()"), "(new Foo())()");
+        assert_eq!(grouped("new a.B(1)"), "(new a.B(1))");
+        assert_eq!(grouped("-new Foo().x"), "(- (new Foo()).x)");
+    }
+
+    #[test]
+    fn new_without_arguments() {
+        assert_eq!(grouped("new Foo"), "(new Foo)");
+        assert_eq!(grouped("new Foo + 1"), "((new Foo) + 1)");
+        round_trip::<Expression>("new /* c */ Foo ( 1 )");
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    ast::{
+        structure::type_struct::Type, terminal::keyword::KwAs, trivia::with_trivia::WithTrivia,
+        utils::flags::Scope,
+    },
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::Expression;
+
+/// `value as integer`, available from version 4.
+///
+/// The cast binds like the relational operators, so `a + b as real` casts
+/// `a + b`. It is parsed within the binary operator chain, see
+/// [`super::binary::BinaryExpression::parse_with`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CastExpression<F: Scope>(
+    pub Expression<F>,
+    pub WithTrivia<KwAs>,
+    pub WithTrivia<Type>,
+);
+impl<V: Visitor, F: Scope> Visitable<V> for CastExpression<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for CastExpression<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::structure::type_struct::{PrimitiveType, SimpleType},
+        parser::{with_version, Version},
+        test_utils::{grouped, rejects, round_trip, Expression},
+    };
+
+    #[test]
+    fn relational_keywords() {
+        assert_eq!(
+            grouped("x instanceof Foo && k in m"),
+            "((x instanceof Foo) && (k in m))"
+        );
+        assert_eq!(grouped("a + b instanceof C"), "((a + b) instanceof C)");
+        assert_eq!(grouped("k in m == true"), "((k in m) == true)");
+        assert_eq!(grouped("a instanceof B"), "(a instanceof B)");
+        rejects::<Expression>("a instanceofB");
+        rejects::<Expression>("a inm");
+    }
+
+    #[test]
+    fn casts() {
+        assert_eq!(grouped("a + b as real"), "((a + b) as real)");
+        assert_eq!(grouped("a as real < c"), "((a as real) < c)");
+        assert_eq!(grouped("a as integer as real"), "((a as integer) as real)");
+        assert_eq!(grouped("a == b as string"), "(a == (b as string))");
+        assert_eq!(grouped("x as Array<integer>"), "(x as Array<integer>)");
+        let Expression::Cast(cast) = round_trip("a + b as  /* t */ integer") else {
+            panic!()
+        };
+        assert!(matches!(cast.0, Expression::Binary(_)));
+        assert!(matches!(
+            cast.2 .1 .0,
+            SimpleType::Primitive(PrimitiveType::Integer)
+        ));
+    }
+
+    #[test]
+    fn casts_need_v4() {
+        with_version(Version::V3, || {
+            rejects::<Expression>("x as integer");
+            assert_eq!(grouped("x + y"), "(x + y)");
+        });
+    }
+}
@@ -0,0 +1,400 @@
+use nom::{
+    branch::alt,
+    combinator::{map, opt},
+    sequence::{pair, tuple},
+};
+
+use crate::{
+    ast::{
+        terminal::symbol::{Colon, Comma, DotDot, LAngle, LBracket, RAngle, RBracket},
+        trivia::with_trivia::{with_trivia, WithTrivia},
+        utils::{
+            flags::Scope,
+            separated::{Separated, Separated0, Separated1},
+        },
+    },
+    parser::{IResult, Parser},
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::{
+    binary::{BinaryExpression, BinaryOperator},
+    Expression,
+};
+
+/// Parses the literals opened by a square bracket: arrays, maps and
+/// intervals. The first element is parsed once and the token following it
+/// decides which literal it belongs to.
+pub(crate) fn parse_bracketed<F: Scope>(input: &str) -> IResult<&str, Expression<F>> {
+    // `]a..b]` and `]a..b[` can only be intervals.
+    if let (rest, Some(rbracket)) = opt(RBracket::parse)(input)? {
+        let (rest, (from, dots, to, end)) = tuple((
+            <_ as Parser<&str>>::parse,
+            <_ as Parser<&str>>::parse,
+            <_ as Parser<&str>>::parse,
+            <_ as Parser<&str>>::parse,
+        ))(rest)?;
+        let interval = IntervalLiteral(IntervalStart::Open(rbracket), from, dots, to, end);
+        return Ok((rest, Expression::Interval(Box::new(interval))));
+    }
+
+    let (input, lbracket) = LBracket::parse(input)?;
+    if let (rest, Some((colon, rbracket))) =
+        opt(pair(<_ as Parser<&str>>::parse, <_ as Parser<&str>>::parse))(input)?
+    {
+        let map = MapLiteral(lbracket, MapEntries::Empty(colon), rbracket);
+        return Ok((rest, Expression::Map(Box::new(map))));
+    }
+
+    let (input, first) = match WithTrivia::<Expression<F>>::parse(input) {
+        Ok(res) => res,
+        Err(nom::Err::Error(_)) => {
+            let (rest, rbracket) = WithTrivia::<RBracket>::parse(input)?;
+            let array = ArrayLiteral(lbracket, Separated(Vec::new(), Vec::new()), rbracket);
+            return Ok((rest, Expression::Array(Box::new(array))));
+        }
+        Err(e) => return Err(e),
+    };
+
+    if let (rest, Some(dots)) = opt(<WithTrivia<DotDot> as Parser<&str>>::parse)(input)? {
+        let (rest, (to, end)) = pair(<_ as Parser<&str>>::parse, <_ as Parser<&str>>::parse)(rest)?;
+        let interval = IntervalLiteral(IntervalStart::Closed(lbracket), first, dots, to, end);
+        return Ok((rest, Expression::Interval(Box::new(interval))));
+    }
+
+    if let (rest, Some((colon, value))) =
+        opt(pair(<_ as Parser<&str>>::parse, <_ as Parser<&str>>::parse))(input)?
+    {
+        let first = WithTrivia(first.0, MapEntry(first.1, colon, value));
+        let (rest, entries) = continue_list(rest, first)?;
+        let (rest, rbracket) = WithTrivia::<RBracket>::parse(rest)?;
+        let map = MapLiteral(lbracket, MapEntries::Entries(entries), rbracket);
+        return Ok((rest, Expression::Map(Box::new(map))));
+    }
+
+    let (rest, Separated(elements, commas)) = continue_list(input, first)?;
+    let (rest, rbracket) = WithTrivia::<RBracket>::parse(rest)?;
+    let array = ArrayLiteral(lbracket, Separated(elements, commas), rbracket);
+    Ok((rest, Expression::Array(Box::new(array))))
+}
+
+/// Parses the remainder of a comma separated list whose first item has
+/// already been parsed, keeping a trailing comma if there is one.
+fn continue_list<'a, T>(
+    input: &'a str,
+    first: T,
+) -> IResult<&'a str, Separated1<T, WithTrivia<Comma>>>
+where
+    T: Parser<&'a str>,
+{
+    let (input, comma) = opt(<WithTrivia<Comma> as Parser<&'a str>>::parse)(input)?;
+    let Some(comma) = comma else {
+        return Ok((input, Separated(vec![first], Vec::new())));
+    };
+    let (input, Separated(items, separators)) =
+        Separated0::<T, WithTrivia<Comma>>::parse_trailing(input)?;
+    Ok((
+        input,
+        Separated(
+            std::iter::once(first).chain(items).collect(),
+            std::iter::once(comma).chain(separators).collect(),
+        ),
+    ))
+}
+
+/// `[1, 2, 3]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArrayLiteral<F: Scope>(
+    pub LBracket,
+    pub Separated0<WithTrivia<Expression<F>>, WithTrivia<Comma>>,
+    pub WithTrivia<RBracket>,
+);
+impl<V: Visitor, F: Scope> Visitable<V> for ArrayLiteral<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for ArrayLiteral<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+/// `[a: 1, b: 2]` or the empty map `[:]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapLiteral<F: Scope>(pub LBracket, pub MapEntries<F>, pub WithTrivia<RBracket>);
+impl<V: Visitor, F: Scope> Visitable<V> for MapLiteral<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for MapLiteral<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MapEntries<F: Scope> {
+    Empty(WithTrivia<Colon>),
+    Entries(Separated1<WithTrivia<MapEntry<F>>, WithTrivia<Comma>>),
+}
+impl<V: Visitor, F: Scope> Visitable<V> for MapEntries<F> {
+    default fn accept(&self, v: &mut V) {
+        match self {
+            Self::Empty(node) => v.visit(node),
+            Self::Entries(node) => v.visit(node),
+        }
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for MapEntries<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        match self {
+            Self::Empty(node) => node.accept_mut(v),
+            Self::Entries(node) => node.accept_mut(v),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapEntry<F: Scope>(
+    pub Expression<F>,
+    pub WithTrivia<Colon>,
+    pub WithTrivia<Expression<F>>,
+);
+impl<'a, F: Scope> Parser<&'a str> for MapEntry<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(key, colon, value)| Self(key, colon, value),
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for MapEntry<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for MapEntry<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+/// `<1, 2, 3>`
+///
+/// Elements bind tighter than comparisons so that `>` closes the set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetLiteral<F: Scope>(
+    pub LAngle,
+    pub Separated0<WithTrivia<Expression<F>>, WithTrivia<Comma>>,
+    pub WithTrivia<RAngle>,
+);
+impl<'a, F: Scope> Parser<&'a str> for SetLiteral<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        let (input, langle) = LAngle::parse(input)?;
+        let (input, elements) = Separated0::parse_trailing_with(
+            input,
+            with_trivia(|i| {
+                BinaryExpression::parse_with(
+                    i,
+                    BinaryOperator::SHIFT_PRECEDENCE,
+                    &mut Expression::parse_unary,
+                )
+            }),
+            WithTrivia::<Comma>::parse,
+        )?;
+        let (input, rangle) = WithTrivia::<RAngle>::parse(input)?;
+        Ok((input, Self(langle, elements, rangle)))
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for SetLiteral<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for SetLiteral<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+/// `[1..10]`, with either bound excluded by turning its bracket around as in
+/// `]1..10[`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalLiteral<F: Scope>(
+    pub IntervalStart,
+    pub WithTrivia<Expression<F>>,
+    pub WithTrivia<DotDot>,
+    pub WithTrivia<Expression<F>>,
+    pub WithTrivia<IntervalEnd>,
+);
+impl<F: Scope> IntervalLiteral<F> {
+    pub fn includes_start(&self) -> bool {
+        matches!(self.0, IntervalStart::Closed(_))
+    }
+
+    pub fn includes_end(&self) -> bool {
+        matches!(self.4 .1, IntervalEnd::Closed(_))
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for IntervalLiteral<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+        v.visit(&self.3);
+        v.visit(&self.4);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for IntervalLiteral<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+        self.3.accept_mut(v);
+        self.4.accept_mut(v);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntervalStart {
+    Closed(LBracket),
+    Open(RBracket),
+}
+impl<V: Visitor> Visitable<V> for IntervalStart {
+    default fn accept(&self, v: &mut V) {
+        match self {
+            Self::Closed(node) => v.visit(node),
+            Self::Open(node) => v.visit(node),
+        }
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for IntervalStart {
+    default fn accept_mut(&mut self, v: &mut V) {
+        match self {
+            Self::Closed(node) => node.accept_mut(v),
+            Self::Open(node) => node.accept_mut(v),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntervalEnd {
+    Closed(RBracket),
+    Open(LBracket),
+}
+impl<'a> Parser<&'a str> for IntervalEnd {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        alt((
+            map(RBracket::parse, Self::Closed),
+            map(LBracket::parse, Self::Open),
+        ))(input)
+    }
+}
+impl<V: Visitor> Visitable<V> for IntervalEnd {
+    default fn accept(&self, v: &mut V) {
+        match self {
+            Self::Closed(node) => v.visit(node),
+            Self::Open(node) => v.visit(node),
+        }
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for IntervalEnd {
+    default fn accept_mut(&mut self, v: &mut V) {
+        match self {
+            Self::Closed(node) => node.accept_mut(v),
+            Self::Open(node) => node.accept_mut(v),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::expressions::collection::MapEntries,
+        test_utils::{grouped, rejects, round_trip, Expression},
+    };
+
+    #[test]
+    fn arrays() {
+        let Expression::Array(array) = round_trip("[1, a + b, [2]]") else {
+            panic!()
+        };
+        assert_eq!(array.1 .0.len(), 3);
+        assert!(matches!(round_trip("[]"), Expression::Array(_)));
+        assert!(matches!(round_trip("[ ]"), Expression::Array(_)));
+        round_trip::<Expression>("[1, 2,]");
+        round_trip::<Expression>("[\n  1, // one\n  2,\n]");
+        rejects::<Expression>("[1,,2]");
+        rejects::<Expression>("[,]");
+    }
+
+    #[test]
+    fn maps() {
+        let Expression::Map(map) = round_trip("[a: 1, 'b': 2,]") else {
+            panic!()
+        };
+        assert!(matches!(&map.1, MapEntries::Entries(entries) if entries.0.len() == 2));
+        let Expression::Map(map) = round_trip("[ : ]") else {
+            panic!()
+        };
+        assert!(matches!(map.1, MapEntries::Empty(_)));
+        round_trip::<Expression>("[:]");
+        rejects::<Expression>("[a: 1, 2]");
+    }
+
+    #[test]
+    fn sets() {
+        assert!(matches!(round_trip("<1, 2>"), Expression::Set(_)));
+        assert!(matches!(round_trip("<>"), Expression::Set(_)));
+        round_trip::<Expression>("<1, 2,>");
+        assert_eq!(grouped("<a + 1, b> < c"), "(<a + 1, b> < c)");
+        assert_eq!(grouped("<a << 1>"), "<a << 1>");
+    }
+
+    #[test]
+    fn intervals() {
+        let Expression::Interval(interval) = round_trip("[1..10]") else {
+            panic!()
+        };
+        assert!(interval.includes_start() && interval.includes_end());
+        let Expression::Interval(interval) = round_trip("]a..b[") else {
+            panic!()
+        };
+        assert!(!interval.includes_start() && !interval.includes_end());
+        let Expression::Interval(interval) = round_trip("[1 .. n[") else {
+            panic!()
+        };
+        assert!(interval.includes_start() && !interval.includes_end());
+        round_trip::<Expression>("]0 .. 1]");
+    }
+
+    #[test]
+    fn subscripts_are_not_literals() {
+        assert_eq!(grouped("x[0]"), "x[0]");
+        assert_eq!(grouped("[1, 2][0]"), "[1, 2][0]");
+        let deep = format!("{}1{}", "[".repeat(40), "]".repeat(40));
+        round_trip::<Expression>(&deep);
+    }
+}
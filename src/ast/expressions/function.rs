@@ -0,0 +1,306 @@
+use nom::{branch::alt, combinator::map, sequence::tuple};
+
+use crate::{
+    ast::{
+        statements::{function_declaration::ReturnType, Statements},
+        structure::{
+            parameter::{Parameter, ParameterList},
+            type_struct::Type,
+        },
+        terminal::{
+            keyword::KwFunction,
+            symbol::{EqualRAngle, LBrace, MinusRAngle, RBrace},
+        },
+        trivia::with_trivia::WithTrivia,
+        utils::flags::Scope,
+    },
+    parser::{IResult, Parser},
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::Expression;
+
+/// `function(a, b) { ... }` or `function(integer a) -> integer { ... }`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionExpression<F: Scope>(
+    pub KwFunction,
+    pub WithTrivia<ParameterList<F>>,
+    pub Option<WithTrivia<ReturnType>>,
+    pub WithTrivia<FunctionBody<F>>,
+);
+impl<F: Scope> FunctionExpression<F> {
+    pub fn return_type(&self) -> Option<&Type> {
+        self.2.as_ref().map(|return_type| &return_type.1 .1 .1)
+    }
+}
+impl<'a, F: Scope> Parser<&'a str> for FunctionExpression<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(function, parameters, return_type, body)| {
+                Self(function, parameters, return_type, body)
+            },
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for FunctionExpression<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+        v.visit(&self.3);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for FunctionExpression<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+        self.3.accept_mut(v);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionBody<F: Scope>(
+    pub LBrace,
+    pub Vec<WithTrivia<Statements<F::Function>>>,
+    pub WithTrivia<RBrace>,
+);
+impl<'a, F: Scope> Parser<&'a str> for FunctionBody<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(lbrace, statements, rbrace)| Self(lbrace, statements, rbrace),
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for FunctionBody<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for FunctionBody<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+/// `x -> x + 1` or `(a, b) => { ... }`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LambdaExpression<F: Scope>(
+    pub LambdaParameters<F>,
+    pub WithTrivia<Arrow>,
+    pub WithTrivia<LambdaBody<F>>,
+);
+impl<'a, F: Scope> Parser<&'a str> for LambdaExpression<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(parameters, arrow, body)| Self(parameters, arrow, body),
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for LambdaExpression<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for LambdaExpression<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LambdaParameters<F: Scope> {
+    Single(Parameter<F>),
+    List(ParameterList<F>),
+}
+impl<'a, F: Scope> Parser<&'a str> for LambdaParameters<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        alt((
+            map(<_ as Parser<&'a str>>::parse, Self::List),
+            map(Parameter::parse_without_default, Self::Single),
+        ))(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for LambdaParameters<F> {
+    default fn accept(&self, v: &mut V) {
+        match self {
+            Self::Single(node) => v.visit(node),
+            Self::List(node) => v.visit(node),
+        }
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for LambdaParameters<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        match self {
+            Self::Single(node) => node.accept_mut(v),
+            Self::List(node) => node.accept_mut(v),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Arrow {
+    Thin(MinusRAngle),
+    Fat(EqualRAngle),
+}
+impl<'a> Parser<&'a str> for Arrow {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        alt((
+            map(MinusRAngle::parse, Self::Thin),
+            map(EqualRAngle::parse, Self::Fat),
+        ))(input)
+    }
+}
+impl<V: Visitor> Visitable<V> for Arrow {
+    default fn accept(&self, v: &mut V) {
+        match self {
+            Self::Thin(node) => v.visit(node),
+            Self::Fat(node) => v.visit(node),
+        }
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for Arrow {
+    default fn accept_mut(&mut self, v: &mut V) {
+        match self {
+            Self::Thin(node) => node.accept_mut(v),
+            Self::Fat(node) => node.accept_mut(v),
+        }
+    }
+}
+
+/// A braced body, or a single expression whose value is returned. A `{`
+/// after the arrow is read as an object literal only when it is not a valid
+/// body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LambdaBody<F: Scope> {
+    Block(FunctionBody<F>),
+    Expression(Expression<F>),
+}
+impl<'a, F: Scope> Parser<&'a str> for LambdaBody<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        alt((
+            map(<_ as Parser<&'a str>>::parse, Self::Block),
+            map(<_ as Parser<&'a str>>::parse, Self::Expression),
+        ))(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for LambdaBody<F> {
+    default fn accept(&self, v: &mut V) {
+        match self {
+            Self::Block(node) => v.visit(node),
+            Self::Expression(node) => v.visit(node),
+        }
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for LambdaBody<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        match self {
+            Self::Block(node) => node.accept_mut(v),
+            Self::Expression(node) => node.accept_mut(v),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LambdaBody, LambdaParameters};
+    use crate::{
+        ast::structure::{
+            file::File,
+            type_struct::{PrimitiveType, SimpleType},
+        },
+        error::ParseErrorKind,
+        test_utils::{error_kind, grouped, rejects, round_trip, Expression},
+    };
+
+    #[test]
+    fn anonymous_functions() {
+        round_trip::<Expression>("function() {}");
+        round_trip::<Expression>("function ( ) { }");
+        let Expression::Function(function) =
+            round_trip("function(a, integer b) { var c = a + b; return c }")
+        else {
+            panic!()
+        };
+        assert!(function.return_type().is_none());
+    }
+
+    #[test]
+    fn anonymous_function_return_types() {
+        let Expression::Function(function) =
+            round_trip("function(integer a) -> integer { return a }")
+        else {
+            panic!()
+        };
+        assert!(matches!(
+            function.return_type().map(|ty| &ty.0),
+            Some(SimpleType::Primitive(PrimitiveType::Integer))
+        ));
+        round_trip::<Expression>("function() /* c */ -> Array<real> {}");
+        rejects::<Expression>("function() -> {}");
+    }
+
+    #[test]
+    fn lambdas() {
+        let Expression::Lambda(lambda) = round_trip("x -> x + 1") else {
+            panic!()
+        };
+        assert!(matches!(lambda.0, LambdaParameters::Single(_)));
+        assert!(matches!(lambda.2 .1, LambdaBody::Expression(_)));
+        let Expression::Lambda(lambda) = round_trip("(a, b) => { return a * b }") else {
+            panic!()
+        };
+        assert!(matches!(lambda.0, LambdaParameters::List(_)));
+        assert!(matches!(lambda.2 .1, LambdaBody::Block(_)));
+        round_trip::<Expression>("(integer a,b)->{}");
+        round_trip::<Expression>("() -> 1");
+        assert!(matches!(round_trip("x -> {a: x}"), Expression::Lambda(_)));
+    }
+
+    #[test]
+    fn lambdas_bind_loosely() {
+        assert_eq!(grouped("f(x -> x + 1, y)"), "f(x -> x + 1, y)");
+        let Expression::Lambda(lambda) = round_trip("x -> y -> x + y") else {
+            panic!()
+        };
+        assert!(matches!(
+            &lambda.2 .1,
+            LambdaBody::Expression(Expression::Lambda(_))
+        ));
+    }
+
+    #[test]
+    fn bodies_are_function_scopes() {
+        round_trip::<File>("var f = function() { return 1 }");
+        round_trip::<File>("var f = () => { for (;;) { break } }");
+        assert_eq!(
+            error_kind::<File>("var f = function() { break }"),
+            ParseErrorKind::BreakOutsideLoop
+        );
+        rejects::<File>("var f = function() { global x = 1; }");
+    }
+}
@@ -1,32 +1,368 @@
-use nom::{combinator::map, IResult};
+use access::{IndexExpression, MemberExpression};
+use assignment::{AssignmentExpression, TernaryExpression};
+use binary::BinaryExpression;
+use call::{CallExpression, NewExpression};
+use cast::CastExpression;
+use collection::{ArrayLiteral, IntervalLiteral, MapLiteral, SetLiteral};
+use function::{FunctionExpression, LambdaExpression};
+use nom::{
+    branch::alt,
+    combinator::{map, opt},
+    error::{ErrorKind, ParseError as _},
+    sequence::{pair, tuple},
+};
+use object::ObjectLiteral;
+use parenthesized::Parenthesized;
+use unary::{PostfixExpression, PrefixExpression};
 
 use crate::{
-    parser::Parser,
+    error::{ParseError, ParseErrorKind},
+    parser::{version, IResult, Parser, Version},
     visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
 };
 
-use super::terminal::identifier::Identifier;
+use super::{
+    terminal::{
+        identifier::Identifier,
+        keyword::{KwFalse, KwNull, KwSuper, KwThis, KwTrue},
+        number::{IntegerLiteral, RealLiteral},
+        string::StringLiteral,
+    },
+    trivia::with_trivia::with_trivia,
+    utils::flags::{ClassFlag, HasFlag, Scope},
+};
+
+pub mod access;
+pub mod assignment;
+pub mod binary;
+pub mod call;
+pub mod cast;
+pub mod collection;
+pub mod function;
+pub mod object;
+pub mod parenthesized;
+pub mod unary;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum Expression {
+pub enum Expression<F: Scope> {
     Identifier(Identifier),
+    Integer(IntegerLiteral),
+    Real(RealLiteral),
+    String(StringLiteral),
+    True(KwTrue),
+    False(KwFalse),
+    Null(KwNull),
+    /// Only allowed in the non-static members of a class.
+    This(HasFlag<ClassFlag, F>, KwThis),
+    /// Only allowed in the non-static members of a class.
+    Super(HasFlag<ClassFlag, F>, KwSuper),
+    Binary(Box<BinaryExpression<F>>),
+    Prefix(Box<PrefixExpression<F>>),
+    Postfix(Box<PostfixExpression<F>>),
+    Call(Box<CallExpression<F>>),
+    New(Box<NewExpression<F>>),
+    Member(Box<MemberExpression<F>>),
+    Index(Box<IndexExpression<F>>),
+    Array(Box<ArrayLiteral<F>>),
+    Map(Box<MapLiteral<F>>),
+    Set(Box<SetLiteral<F>>),
+    Interval(Box<IntervalLiteral<F>>),
+    Object(Box<ObjectLiteral<F>>),
+    Function(Box<FunctionExpression<F>>),
+    Lambda(Box<LambdaExpression<F>>),
+    Ternary(Box<TernaryExpression<F>>),
+    Assignment(Box<AssignmentExpression<F>>),
+    Parenthesized(Box<Parenthesized<F>>),
+    Cast(Box<CastExpression<F>>),
+}
+impl<F: Scope> Expression<F> {
+    /// Parses a conditional expression, followed by an assignment operator
+    /// and its value if there is one. The value is parsed as a full
+    /// expression so that `a = b = c` assigns `b = c` to `a`.
+    fn parse_assignment(input: &str) -> IResult<&str, Self> {
+        let (rest, node) = Self::parse_conditional(input)?;
+        match opt(pair(<_ as Parser<&str>>::parse, <_ as Parser<&str>>::parse))(rest)? {
+            (_, Some(_)) if !node.is_assignable() => Err(nom::Err::Failure(ParseError::new(
+                input,
+                ParseErrorKind::InvalidAssignmentTarget,
+            ))),
+            (rest, Some((op, value))) => Ok((
+                rest,
+                Self::Assignment(Box::new(AssignmentExpression(node, op, value))),
+            )),
+            (rest, None) => Ok((rest, node)),
+        }
+    }
+
+    /// Whether the expression can be assigned to, or incremented: a
+    /// variable, a member or an element.
+    pub fn is_assignable(&self) -> bool {
+        matches!(self, Self::Identifier(_) | Self::Member(_) | Self::Index(_))
+    }
+
+    /// Parses a binary expression, followed by `? then : otherwise` if there
+    /// is one. The `otherwise` branch does not take an assignment, so that
+    /// `a ? b : c = d` assigns to the whole conditional and is rejected.
+    fn parse_conditional(input: &str) -> IResult<&str, Self> {
+        let (input, node) = BinaryExpression::parse_with(input, 0, &mut Self::parse_unary)?;
+        match opt(tuple((
+            <_ as Parser<&str>>::parse,
+            <_ as Parser<&str>>::parse,
+            <_ as Parser<&str>>::parse,
+            with_trivia(alt((
+                map(<_ as Parser<&str>>::parse, |node| {
+                    Self::Lambda(Box::new(node))
+                }),
+                Self::parse_conditional,
+            ))),
+        )))(input)?
+        {
+            (input, Some((question, then, colon, otherwise))) => Ok((
+                input,
+                Self::Ternary(Box::new(TernaryExpression(
+                    node, question, then, colon, otherwise,
+                ))),
+            )),
+            (input, None) => Ok((input, node)),
+        }
+    }
+
+    /// Parses the operand of a binary operator: prefix operators applied to
+    /// a postfix expression, so that `-a.b` negates `a.b`.
+    pub(crate) fn parse_unary(input: &str) -> IResult<&str, Self> {
+        alt((
+            map(PrefixExpression::parse, |node| Self::Prefix(Box::new(node))),
+            Self::parse_postfix,
+        ))(input)
+    }
+
+    /// Parses a primary expression followed by a left-nested chain of calls,
+    /// member accesses and subscripts, then an optional `++` or `--`, which
+    /// needs an assignable operand.
+    fn parse_postfix(start: &str) -> IResult<&str, Self> {
+        let (mut input, mut node) = Self::parse_primary(start)?;
+        loop {
+            if let (rest, Some(args)) = opt(<_ as Parser<&str>>::parse)(input)? {
+                node = Self::Call(Box::new(CallExpression(node, args)));
+                input = rest;
+            } else if let (rest, Some((dot, name))) = opt(pair(
+                <_ as Parser<&str>>::parse,
+                with_trivia(Identifier::parse_name),
+            ))(input)?
+            {
+                node = Self::Member(Box::new(MemberExpression(node, dot, name)));
+                input = rest;
+            } else if let (rest, Some((lbracket, index, rbracket))) = opt(tuple((
+                <_ as Parser<&str>>::parse,
+                <_ as Parser<&str>>::parse,
+                <_ as Parser<&str>>::parse,
+            )))(input)?
+            {
+                node = Self::Index(Box::new(IndexExpression(node, lbracket, index, rbracket)));
+                input = rest;
+            } else {
+                break;
+            }
+        }
+        match opt(<_ as Parser<&str>>::parse)(input)? {
+            (_, Some(_)) if !node.is_assignable() => Err(nom::Err::Failure(ParseError::new(
+                start,
+                ParseErrorKind::InvalidAssignmentTarget,
+            ))),
+            (input, Some(op)) => Ok((input, Self::Postfix(Box::new(PostfixExpression(node, op))))),
+            (input, None) => Ok((input, node)),
+        }
+    }
+
+    /// Parses `this` or `super`, which are only allowed in the non-static
+    /// members of a class. Before version 2 both are plain identifiers.
+    fn parse_this_or_super(input: &str) -> IResult<&str, Self> {
+        if version() < Version::V2 {
+            return Err(nom::Err::Error(ParseError::from_error_kind(
+                input,
+                ErrorKind::Tag,
+            )));
+        }
+        if let (rest, Some(kw_this)) = opt(KwThis::parse)(input)? {
+            let flag = HasFlag::require(input, ParseErrorKind::ThisOutsideClass)?;
+            return Ok((rest, Self::This(flag, kw_this)));
+        }
+        let (rest, kw_super) = KwSuper::parse(input)?;
+        let flag = HasFlag::require(input, ParseErrorKind::SuperOutsideClass)?;
+        Ok((rest, Self::Super(flag, kw_super)))
+    }
+
+    pub(crate) fn parse_primary(input: &str) -> IResult<&str, Self> {
+        alt((
+            map(<_ as Parser<&str>>::parse, Self::Real),
+            map(<_ as Parser<&str>>::parse, Self::Integer),
+            map(<_ as Parser<&str>>::parse, Self::String),
+            map(<_ as Parser<&str>>::parse, Self::True),
+            map(<_ as Parser<&str>>::parse, Self::False),
+            map(<_ as Parser<&str>>::parse, Self::Null),
+            Self::parse_this_or_super,
+            map(<_ as Parser<&str>>::parse, |node| Self::New(Box::new(node))),
+            map(<_ as Parser<&str>>::parse, Self::Identifier),
+            map(<_ as Parser<&str>>::parse, |node| {
+                Self::Parenthesized(Box::new(node))
+            }),
+            collection::parse_bracketed,
+            map(<_ as Parser<&str>>::parse, |node| Self::Set(Box::new(node))),
+            map(<_ as Parser<&str>>::parse, |node| {
+                Self::Object(Box::new(node))
+            }),
+            map(<_ as Parser<&str>>::parse, |node| {
+                Self::Function(Box::new(node))
+            }),
+        ))(input)
+    }
 }
-impl<'a> Parser<&'a str> for Expression {
+impl<'a, F: Scope> Parser<&'a str> for Expression<F> {
     fn parse(input: &'a str) -> IResult<&'a str, Self> {
-        map(<_ as Parser<&'a str>>::parse, Self::Identifier)(input)
+        alt((
+            map(<_ as Parser<&'a str>>::parse, |node| {
+                Self::Lambda(Box::new(node))
+            }),
+            Self::parse_assignment,
+        ))(input)
     }
 }
-impl<V: Visitor> Visitable<V> for Expression {
+impl<V: Visitor, F: Scope> Visitable<V> for Expression<F> {
     default fn accept(&self, v: &mut V) {
         match self {
             Self::Identifier(node) => v.visit(node),
+            Self::Integer(node) => v.visit(node),
+            Self::Real(node) => v.visit(node),
+            Self::String(node) => v.visit(node),
+            Self::True(node) => v.visit(node),
+            Self::False(node) => v.visit(node),
+            Self::Null(node) => v.visit(node),
+            Self::This(flag, node) => {
+                v.visit(flag);
+                v.visit(node);
+            }
+            Self::Super(flag, node) => {
+                v.visit(flag);
+                v.visit(node);
+            }
+            Self::Binary(node) => v.visit(node),
+            Self::Prefix(node) => v.visit(node),
+            Self::Postfix(node) => v.visit(node),
+            Self::Call(node) => v.visit(node),
+            Self::New(node) => v.visit(node),
+            Self::Member(node) => v.visit(node),
+            Self::Index(node) => v.visit(node),
+            Self::Array(node) => v.visit(node),
+            Self::Map(node) => v.visit(node),
+            Self::Set(node) => v.visit(node),
+            Self::Interval(node) => v.visit(node),
+            Self::Object(node) => v.visit(node),
+            Self::Function(node) => v.visit(node),
+            Self::Lambda(node) => v.visit(node),
+            Self::Ternary(node) => v.visit(node),
+            Self::Assignment(node) => v.visit(node),
+            Self::Parenthesized(node) => v.visit(node),
+            Self::Cast(node) => v.visit(node),
         }
     }
 }
-impl<V: VisitorMut> VisitableMut<V> for Expression {
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for Expression<F> {
     default fn accept_mut(&mut self, v: &mut V) {
         match self {
             Self::Identifier(node) => node.accept_mut(v),
+            Self::Integer(node) => node.accept_mut(v),
+            Self::Real(node) => node.accept_mut(v),
+            Self::String(node) => node.accept_mut(v),
+            Self::True(node) => node.accept_mut(v),
+            Self::False(node) => node.accept_mut(v),
+            Self::Null(node) => node.accept_mut(v),
+            Self::This(flag, node) => {
+                flag.accept_mut(v);
+                node.accept_mut(v);
+            }
+            Self::Super(flag, node) => {
+                flag.accept_mut(v);
+                node.accept_mut(v);
+            }
+            Self::Binary(node) => node.accept_mut(v),
+            Self::Prefix(node) => node.accept_mut(v),
+            Self::Postfix(node) => node.accept_mut(v),
+            Self::Call(node) => node.accept_mut(v),
+            Self::New(node) => node.accept_mut(v),
+            Self::Member(node) => node.accept_mut(v),
+            Self::Index(node) => node.accept_mut(v),
+            Self::Array(node) => node.accept_mut(v),
+            Self::Map(node) => node.accept_mut(v),
+            Self::Set(node) => node.accept_mut(v),
+            Self::Interval(node) => node.accept_mut(v),
+            Self::Object(node) => node.accept_mut(v),
+            Self::Function(node) => node.accept_mut(v),
+            Self::Lambda(node) => node.accept_mut(v),
+            Self::Ternary(node) => node.accept_mut(v),
+            Self::Assignment(node) => node.accept_mut(v),
+            Self::Parenthesized(node) => node.accept_mut(v),
+            Self::Cast(node) => node.accept_mut(v),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::{statements::class_declaration::InstanceScope, structure::file::File},
+        error::ParseErrorKind,
+        parser::{with_version, Version},
+        test_utils::{error_kind, round_trip, Expression},
+    };
+
+    type InClass = super::Expression<InstanceScope>;
+
+    #[test]
+    fn literal_keywords() {
+        assert!(matches!(round_trip("true"), Expression::True(_)));
+        assert!(matches!(round_trip("false"), Expression::False(_)));
+        assert!(matches!(round_trip("null"), Expression::Null(_)));
+        assert!(matches!(round_trip("trueish"), Expression::Identifier(_)));
+        assert!(matches!(round_trip("nullable"), Expression::Identifier(_)));
+    }
+
+    #[test]
+    fn this_and_super_in_classes() {
+        let InClass::Binary(sum) = round_trip("this.x + super.m()") else {
+            panic!()
+        };
+        assert!(matches!(&sum.0, InClass::Member(member) if matches!(member.0, InClass::This(..))));
+        let InClass::Call(call) = &sum.2 .1 else {
+            panic!()
+        };
+        assert!(
+            matches!(&call.0, InClass::Member(member) if matches!(member.0, InClass::Super(..)))
+        );
+        round_trip::<File>("class A extends B { m() { return this.x + super.m() } }");
+        round_trip::<File>("class A { constructor() { this.x = 1 } }");
+    }
+
+    #[test]
+    fn this_and_super_outside_classes() {
+        assert_eq!(
+            error_kind::<Expression>("this"),
+            ParseErrorKind::ThisOutsideClass
+        );
+        assert_eq!(
+            error_kind::<File>("var a = this"),
+            ParseErrorKind::ThisOutsideClass
+        );
+        assert_eq!(
+            error_kind::<File>("function f() { return super.x }"),
+            ParseErrorKind::SuperOutsideClass
+        );
+    }
+
+    #[test]
+    fn this_and_super_are_names_in_v1() {
+        with_version(Version::V1, || {
+            assert!(matches!(round_trip("this"), Expression::Identifier(_)));
+            assert!(matches!(round_trip("super"), Expression::Identifier(_)));
+        });
+    }
+}
@@ -0,0 +1,138 @@
+use nom::{combinator::map, sequence::tuple};
+
+use crate::{
+    ast::{
+        terminal::{
+            identifier::Identifier,
+            symbol::{Colon, Comma, LBrace, RBrace},
+        },
+        trivia::with_trivia::WithTrivia,
+        utils::{flags::Scope, separated::Separated0},
+    },
+    parser::{IResult, Parser},
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::Expression;
+
+/// `{a: 1, b: "x"}`
+///
+/// In statement position a `{` always opens a block, as in JavaScript, so an
+/// object literal can only appear where an expression is expected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectLiteral<F: Scope>(
+    pub LBrace,
+    pub Separated0<WithTrivia<ObjectEntry<F>>, WithTrivia<Comma>>,
+    pub WithTrivia<RBrace>,
+);
+impl<'a, F: Scope> Parser<&'a str> for ObjectLiteral<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                Separated0::parse_trailing,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(lbrace, entries, rbrace)| Self(lbrace, entries, rbrace),
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for ObjectLiteral<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for ObjectLiteral<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+/// `key: value`, where the key may be a reserved word as in `{class: 1}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectEntry<F: Scope>(
+    pub Identifier,
+    pub WithTrivia<Colon>,
+    pub WithTrivia<Expression<F>>,
+);
+impl<'a, F: Scope> Parser<&'a str> for ObjectEntry<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                Identifier::parse_name,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(key, colon, value)| Self(key, colon, value),
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for ObjectEntry<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for ObjectEntry<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::{
+            expressions, statements::Statements, structure::file::File, utils::separated::Separated,
+        },
+        test_utils::{grouped, rejects, round_trip, Expression},
+    };
+
+    #[test]
+    fn objects() {
+        let Expression::Object(object) = round_trip("{a: 1, b: \"x\"}") else {
+            panic!()
+        };
+        assert_eq!(object.1 .0.len(), 2);
+        assert!(matches!(round_trip("{}"), Expression::Object(_)));
+        let Expression::Object(object) = round_trip("{ class : [1], d: {e: 2}, }") else {
+            panic!()
+        };
+        let Separated(entries, commas) = &object.1;
+        assert_eq!((entries.len(), commas.len()), (2, 2));
+        assert_eq!(entries[0].1 .0 .0, "class");
+        assert!(matches!(entries[0].1 .2 .1, Expression::Array(_)));
+        assert!(matches!(entries[1].1 .2 .1, Expression::Object(_)));
+        assert_eq!(grouped("{a: 1}.a"), "{a: 1}.a");
+        rejects::<Expression>("{a: 1,, b: 2}");
+        rejects::<Expression>("{1: 2}");
+    }
+
+    #[test]
+    fn braces_open_blocks_in_statement_position() {
+        let file = round_trip::<File>("{}\nvar a = {}\nf({})\n");
+        assert!(matches!(file.statements[0].1, Statements::Block(_)));
+        assert!(matches!(
+            file.statements[1].1,
+            Statements::VariableDeclaration(_)
+        ));
+        let Statements::Expression(statement) = &file.statements[2].1 else {
+            panic!()
+        };
+        let expressions::Expression::Call(call) = &statement.0 else {
+            panic!()
+        };
+        assert!(matches!(
+            call.1 .1 .1 .0[0].1,
+            expressions::Expression::Object(_)
+        ));
+        rejects::<File>("{a: 1}");
+    }
+}
@@ -0,0 +1,96 @@
+use nom::{combinator::map, sequence::tuple};
+
+use crate::{
+    ast::{
+        terminal::symbol::{LParen, RParen},
+        trivia::with_trivia::WithTrivia,
+        utils::flags::Scope,
+    },
+    parser::{IResult, Parser},
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::Expression;
+
+/// `(expression)`
+///
+/// Grouping is kept in the tree, with the trivia around both parentheses, so
+/// that the source is written back as it was and tools can tell which
+/// parentheses the user wrote.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Parenthesized<F: Scope>(
+    pub LParen,
+    pub WithTrivia<Expression<F>>,
+    pub WithTrivia<RParen>,
+);
+impl<F: Scope> Parenthesized<F> {
+    pub fn inner(&self) -> &Expression<F> {
+        &self.1 .1
+    }
+}
+impl<'a, F: Scope> Parser<&'a str> for Parenthesized<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(lparen, inner, rparen)| Self(lparen, inner, rparen),
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for Parenthesized<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for Parenthesized<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{grouped, rejects, round_trip, Expression};
+
+    #[test]
+    fn grouping() {
+        assert_eq!(grouped("(a + b) * c"), "((a + b) * c)");
+        assert_eq!(grouped("a - (b - c)"), "(a - (b - c))");
+        assert_eq!(grouped("((x)).y()"), "((x)).y()");
+        let Expression::Parenthesized(node) = round_trip("((x))") else {
+            panic!()
+        };
+        assert!(matches!(node.inner(), Expression::Parenthesized(_)));
+    }
+
+    #[test]
+    fn trivia_is_kept() {
+        let Expression::Binary(product) = round_trip("( /* c */ 1 + 2 ) * (3)") else {
+            panic!()
+        };
+        let Expression::Parenthesized(lhs) = &product.0 else {
+            panic!()
+        };
+        assert!(matches!(lhs.inner(), Expression::Binary(_)));
+        assert!(matches!(product.2 .1, Expression::Parenthesized(_)));
+        let Expression::Parenthesized(node) = round_trip("(\n  a\n)") else {
+            panic!()
+        };
+        assert!(matches!(node.inner(), Expression::Identifier(_)));
+    }
+
+    #[test]
+    fn lambdas_are_not_parenthesized() {
+        assert!(matches!(round_trip("(a) -> a"), Expression::Lambda(_)));
+        assert!(matches!(round_trip("(a)"), Expression::Parenthesized(_)));
+        rejects::<Expression>("()");
+        rejects::<Expression>("(a");
+    }
+}
@@ -0,0 +1,207 @@
+use nom::{branch::alt, combinator::map};
+
+use crate::{
+    ast::{
+        terminal::{
+            keyword::KwNot,
+            reference::Reference,
+            symbol::{Exclamation, Minus, MinusMinus, Plus, PlusPlus, Tilde},
+        },
+        trivia::with_trivia::{with_trivia, WithTrivia},
+        utils::flags::Scope,
+    },
+    error::{ParseError, ParseErrorKind},
+    parser::{IResult, Parser},
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::Expression;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixExpression<F: Scope>(pub PrefixOperator, pub WithTrivia<Expression<F>>);
+impl<'a, F: Scope> Parser<&'a str> for PrefixExpression<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        let (operand_start, op) = PrefixOperator::parse(input)?;
+        let (rest, operand) = with_trivia(Expression::parse_unary)(operand_start)?;
+        if op.is_update() && !operand.1.is_assignable() {
+            return Err(nom::Err::Failure(ParseError::new(
+                operand_start,
+                ParseErrorKind::InvalidAssignmentTarget,
+            )));
+        }
+        Ok((rest, Self(op, operand)))
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for PrefixExpression<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for PrefixExpression<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrefixOperator {
+    Neg(Minus),
+    Plus(Plus),
+    Not(Exclamation),
+    NotKw(KwNot),
+    BitNot(Tilde),
+    Increment(PlusPlus),
+    Decrement(MinusMinus),
+    /// The v1 `@` marker, passing the operand by reference.
+    Reference(Reference),
+}
+impl PrefixOperator {
+    /// Whether the operator is `++` or `--`, which assign to their operand.
+    pub fn is_update(&self) -> bool {
+        matches!(self, Self::Increment(_) | Self::Decrement(_))
+    }
+}
+impl<'a> Parser<&'a str> for PrefixOperator {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        alt((
+            map(PlusPlus::parse, Self::Increment),
+            map(MinusMinus::parse, Self::Decrement),
+            map(Minus::parse, Self::Neg),
+            map(Plus::parse, Self::Plus),
+            map(Exclamation::parse, Self::Not),
+            map(KwNot::parse, Self::NotKw),
+            map(Tilde::parse, Self::BitNot),
+            map(Reference::parse, Self::Reference),
+        ))(input)
+    }
+}
+impl<V: Visitor> Visitable<V> for PrefixOperator {
+    default fn accept(&self, v: &mut V) {
+        match self {
+            Self::Neg(node) => v.visit(node),
+            Self::Plus(node) => v.visit(node),
+            Self::Not(node) => v.visit(node),
+            Self::NotKw(node) => v.visit(node),
+            Self::BitNot(node) => v.visit(node),
+            Self::Increment(node) => v.visit(node),
+            Self::Decrement(node) => v.visit(node),
+            Self::Reference(node) => v.visit(node),
+        }
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for PrefixOperator {
+    default fn accept_mut(&mut self, v: &mut V) {
+        match self {
+            Self::Neg(node) => node.accept_mut(v),
+            Self::Plus(node) => node.accept_mut(v),
+            Self::Not(node) => node.accept_mut(v),
+            Self::NotKw(node) => node.accept_mut(v),
+            Self::BitNot(node) => node.accept_mut(v),
+            Self::Increment(node) => node.accept_mut(v),
+            Self::Decrement(node) => node.accept_mut(v),
+            Self::Reference(node) => node.accept_mut(v),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostfixExpression<F: Scope>(pub Expression<F>, pub WithTrivia<PostfixOperator>);
+impl<V: Visitor, F: Scope> Visitable<V> for PostfixExpression<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for PostfixExpression<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PostfixOperator {
+    Increment(PlusPlus),
+    Decrement(MinusMinus),
+}
+impl<'a> Parser<&'a str> for PostfixOperator {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        alt((
+            map(PlusPlus::parse, Self::Increment),
+            map(MinusMinus::parse, Self::Decrement),
+        ))(input)
+    }
+}
+impl<V: Visitor> Visitable<V> for PostfixOperator {
+    default fn accept(&self, v: &mut V) {
+        match self {
+            Self::Increment(node) => v.visit(node),
+            Self::Decrement(node) => v.visit(node),
+        }
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for PostfixOperator {
+    default fn accept_mut(&mut self, v: &mut V) {
+        match self {
+            Self::Increment(node) => node.accept_mut(v),
+            Self::Decrement(node) => node.accept_mut(v),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::ParseErrorKind,
+        parser::{with_version, Version},
+        test_utils::{error_kind, grouped, rejects, Expression},
+    };
+
+    #[test]
+    fn prefix_operators() {
+        assert_eq!(grouped("-a * b"), "((- a) * b)");
+        assert_eq!(grouped("!a && not b"), "((! a) && (not b))");
+        assert_eq!(grouped("~a | +b"), "((~ a) | (+ b))");
+        assert_eq!(grouped("- - -a"), "(- (- (- a)))");
+        assert_eq!(grouped("-a.b"), "(- a.b)");
+        assert_eq!(grouped("!f()"), "(! f())");
+        assert_eq!(grouped("-2 ** 2"), "((- 2) ** 2)");
+    }
+
+    #[test]
+    fn increments() {
+        assert_eq!(grouped("++i + j--"), "((++ i) + (j--))");
+        assert_eq!(grouped("--a.b"), "(-- a.b)");
+        assert_eq!(grouped("a[0]++"), "(a[0]++)");
+        assert_eq!(grouped("i++ < n"), "((i++) < n)");
+        assert_eq!(grouped("a - -b"), "(a - (- b))");
+    }
+
+    #[test]
+    fn increments_need_assignable_operands() {
+        for src in ["++1", "1++", "--(a)", "(a)--", "++a++", "++-a", "a.b()--"] {
+            assert_eq!(
+                error_kind::<Expression>(src),
+                ParseErrorKind::InvalidAssignmentTarget,
+                "{src}"
+            );
+        }
+    }
+
+    #[test]
+    fn writer_round_trip() {
+        assert_eq!(grouped("+ /* c */ a--"), "(+ (a--))");
+        assert_eq!(grouped("not\n  a"), "(not a)");
+        assert_eq!(grouped("-- k"), "(-- k)");
+    }
+
+    #[test]
+    fn v1_references() {
+        with_version(Version::V1, || {
+            assert_eq!(grouped("@a"), "(@ a)");
+        });
+        rejects::<Expression>("@a");
+    }
+}
@@ -1,59 +1,80 @@
-use nom::{combinator::map, sequence::pair, IResult};
+use nom::{combinator::map, sequence::tuple};
 
 use crate::{
     ast::{
         expressions::Expression,
         terminal::{
             identifier::Identifier,
+            reference::Reference,
             symbol::{Comma, Equal},
         },
         trivia::with_trivia::WithTrivia,
-        utils::separated::Separated1,
+        utils::{flags::Scope, separated::Separated1},
     },
-    parser::Parser,
+    parser::{IResult, Parser},
     visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct AssignmentList(pub Separated1<WithTrivia<Assignment>, WithTrivia<Comma>>);
-impl<'a> Parser<&'a str> for AssignmentList {
+pub struct AssignmentList<F: Scope>(pub Separated1<WithTrivia<Assignment<F>>, WithTrivia<Comma>>);
+impl<F: Scope> AssignmentList<F> {
+    /// The declared names, in order.
+    pub fn names(&self) -> impl Iterator<Item = &Identifier> {
+        self.0 .0.iter().map(|assignment| assignment.1.name())
+    }
+}
+impl<'a, F: Scope> Parser<&'a str> for AssignmentList<F> {
     fn parse(input: &'a str) -> IResult<&'a str, Self> {
         map(<_ as Parser<&'a str>>::parse, Self)(input)
     }
 }
-impl<V: Visitor> Visitable<V> for AssignmentList {
+impl<V: Visitor, F: Scope> Visitable<V> for AssignmentList<F> {
     default fn accept(&self, v: &mut V) {
         v.visit(&self.0);
     }
 }
-impl<V: VisitorMut> VisitableMut<V> for AssignmentList {
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for AssignmentList<F> {
     default fn accept_mut(&mut self, v: &mut V) {
         self.0.accept_mut(v);
     }
 }
 
+/// A declared name with its optional initial value. In v1 the name may be
+/// marked `@` to bind the value by reference.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Assignment(
+pub struct Assignment<F: Scope>(
+    Option<Reference>,
     Identifier,
-    Option<(WithTrivia<Equal>, WithTrivia<Expression>)>,
+    Option<(WithTrivia<Equal>, WithTrivia<Expression<F>>)>,
 );
-impl<'a> Parser<&'a str> for Assignment {
+impl<F: Scope> Assignment<F> {
+    pub fn name(&self) -> &Identifier {
+        &self.1
+    }
+}
+impl<'a, F: Scope> Parser<&'a str> for Assignment<F> {
     fn parse(input: &'a str) -> IResult<&'a str, Self> {
         map(
-            pair(<_ as Parser<&'a str>>::parse, <_ as Parser<&'a str>>::parse),
-            |(first, last)| Self(first, last),
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(reference, name, value)| Self(reference, name, value),
         )(input)
     }
 }
-impl<V: Visitor> Visitable<V> for Assignment {
+impl<V: Visitor, F: Scope> Visitable<V> for Assignment<F> {
     default fn accept(&self, v: &mut V) {
         v.visit(&self.0);
         v.visit(&self.1);
+        v.visit(&self.2);
     }
 }
-impl<V: VisitorMut> VisitableMut<V> for Assignment {
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for Assignment<F> {
     default fn accept_mut(&mut self, v: &mut V) {
         self.0.accept_mut(v);
         self.1.accept_mut(v);
+        self.2.accept_mut(v);
     }
 }
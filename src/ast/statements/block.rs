@@ -0,0 +1,74 @@
+use nom::{combinator::map, sequence::tuple};
+
+use crate::{
+    ast::{
+        terminal::symbol::{LBrace, RBrace},
+        trivia::with_trivia::WithTrivia,
+        utils::flags::Scope,
+    },
+    parser::{IResult, Parser},
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::Statements;
+
+/// `{ ... }`, a nested scope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block<F: Scope>(
+    pub LBrace,
+    pub Vec<WithTrivia<Statements<F::Block>>>,
+    pub WithTrivia<RBrace>,
+);
+impl<'a, F: Scope> Parser<&'a str> for Block<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(lbrace, statements, rbrace)| Self(lbrace, statements, rbrace),
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for Block<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for Block<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::{statements::Statements, structure::file::File},
+        test_utils::{rejects, round_trip},
+    };
+
+    #[test]
+    fn blocks() {
+        let file = round_trip::<File>("global g = 1\n{ var a = 1; { b = a } }\n{}");
+        let Statements::Block(block) = &file.statements[1].1 else {
+            panic!("{:?}", file.statements[1]);
+        };
+        assert_eq!(block.1.len(), 2);
+        assert!(matches!(block.1[1].1, Statements::Block(_)));
+        round_trip::<File>("{\n    // only a comment\n}");
+        rejects::<File>("{ var a = 1");
+    }
+
+    #[test]
+    fn global_only_at_top_level() {
+        rejects::<File>("{ global g = 1 }");
+        rejects::<File>("{ { global g } }");
+        round_trip::<File>("global g\n{ g = 1 }");
+    }
+}
@@ -0,0 +1,513 @@
+use nom::{
+    branch::alt,
+    combinator::{map, opt},
+    sequence::{pair, tuple},
+};
+
+use crate::{
+    ast::{
+        expressions::Expression,
+        structure::{parameter::ParameterList, type_struct::TypeFollowedById},
+        terminal::{
+            identifier::Identifier,
+            keyword::{
+                KwClass, KwConstructor, KwExtends, KwFinal, KwPrivate, KwProtected, KwPublic,
+                KwStatic,
+            },
+            symbol::{Equal, LBrace, RBrace, Semi},
+        },
+        trivia::with_trivia::{with_trivia, WithTrivia},
+        utils::flags::{ClassFlag, Cons, Nil, Scope},
+    },
+    parser::{IResult, Parser},
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::{block::Block, end_of_statement, function_declaration::ReturnType};
+
+/// Flags in effect in the non-static members of a class, where `this` and
+/// `super` are allowed. Static members are parsed with [`Nil`].
+pub type InstanceScope = Cons<ClassFlag, Nil>;
+
+/// Flags in effect in the body of a constructor or of a non-static method.
+pub type MemberScope = <InstanceScope as Scope>::Function;
+
+/// `class Name extends Parent { ... }`
+///
+/// Only allowed at the top level, see [`super::Statements::ClassDeclaration`].
+/// Members are parsed with the [`InstanceScope`] unless they are static.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassDeclaration(
+    pub KwClass,
+    pub WithTrivia<Identifier>,
+    pub Option<WithTrivia<Extends>>,
+    pub WithTrivia<LBrace>,
+    pub Vec<WithTrivia<ClassMember>>,
+    pub WithTrivia<RBrace>,
+);
+impl ClassDeclaration {
+    pub fn name(&self) -> &Identifier {
+        &self.1 .1
+    }
+
+    pub fn parent(&self) -> Option<&Identifier> {
+        self.2.as_ref().map(|extends| &extends.1 .1 .1)
+    }
+
+    pub fn members(&self) -> impl Iterator<Item = &ClassMember> {
+        self.4.iter().map(|member| &member.1)
+    }
+}
+impl<'a> Parser<&'a str> for ClassDeclaration {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(class, name, extends, lbrace, members, rbrace)| {
+                Self(class, name, extends, lbrace, members, rbrace)
+            },
+        )(input)
+    }
+}
+impl<V: Visitor> Visitable<V> for ClassDeclaration {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+        v.visit(&self.3);
+        v.visit(&self.4);
+        v.visit(&self.5);
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for ClassDeclaration {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+        self.3.accept_mut(v);
+        self.4.accept_mut(v);
+        self.5.accept_mut(v);
+    }
+}
+
+/// `extends Parent`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Extends(pub KwExtends, pub WithTrivia<Identifier>);
+impl<'a> Parser<&'a str> for Extends {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            pair(<_ as Parser<&'a str>>::parse, <_ as Parser<&'a str>>::parse),
+            |(extends, parent)| Self(extends, parent),
+        )(input)
+    }
+}
+impl<V: Visitor> Visitable<V> for Extends {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for Extends {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+    }
+}
+
+/// A member of a class with its modifiers, `public static final x = 1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassMember(
+    pub Vec<WithTrivia<Modifier>>,
+    pub WithTrivia<ClassMemberKind>,
+);
+impl ClassMember {
+    pub fn modifiers(&self) -> impl Iterator<Item = &Modifier> {
+        self.0.iter().map(|modifier| &modifier.1)
+    }
+
+    pub fn is_static(&self) -> bool {
+        self.modifiers()
+            .any(|modifier| matches!(modifier, Modifier::Static(_)))
+    }
+
+    pub fn is_final(&self) -> bool {
+        self.modifiers()
+            .any(|modifier| matches!(modifier, Modifier::Final(_)))
+    }
+
+    pub fn kind(&self) -> &ClassMemberKind {
+        &self.1 .1
+    }
+}
+impl<'a> Parser<&'a str> for ClassMember {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        let (input, modifiers) = Vec::<WithTrivia<Modifier>>::parse(input)?;
+        let is_static = modifiers
+            .iter()
+            .any(|modifier| matches!(modifier.1, Modifier::Static(_)));
+        let (input, kind) = if is_static {
+            with_trivia(ClassMemberKind::parse_static)(input)?
+        } else {
+            with_trivia(ClassMemberKind::parse_instance)(input)?
+        };
+        Ok((input, Self(modifiers, kind)))
+    }
+}
+impl<V: Visitor> Visitable<V> for ClassMember {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for ClassMember {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Modifier {
+    Public(KwPublic),
+    Protected(KwProtected),
+    Private(KwPrivate),
+    Static(KwStatic),
+    Final(KwFinal),
+}
+impl<'a> Parser<&'a str> for Modifier {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        alt((
+            map(KwPublic::parse, Self::Public),
+            map(KwProtected::parse, Self::Protected),
+            map(KwPrivate::parse, Self::Private),
+            map(KwStatic::parse, Self::Static),
+            map(KwFinal::parse, Self::Final),
+        ))(input)
+    }
+}
+impl<V: Visitor> Visitable<V> for Modifier {
+    default fn accept(&self, v: &mut V) {
+        match self {
+            Self::Public(node) => v.visit(node),
+            Self::Protected(node) => v.visit(node),
+            Self::Private(node) => v.visit(node),
+            Self::Static(node) => v.visit(node),
+            Self::Final(node) => v.visit(node),
+        }
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for Modifier {
+    default fn accept_mut(&mut self, v: &mut V) {
+        match self {
+            Self::Public(node) => node.accept_mut(v),
+            Self::Protected(node) => node.accept_mut(v),
+            Self::Private(node) => node.accept_mut(v),
+            Self::Static(node) => node.accept_mut(v),
+            Self::Final(node) => node.accept_mut(v),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClassMemberKind {
+    Constructor(Constructor),
+    Method(Method<InstanceScope>),
+    Field(Field<InstanceScope>),
+    StaticMethod(Method<Nil>),
+    StaticField(Field<Nil>),
+}
+impl ClassMemberKind {
+    fn parse_instance(input: &str) -> IResult<&str, Self> {
+        alt((
+            map(<_ as Parser<&str>>::parse, Self::Constructor),
+            map(<_ as Parser<&str>>::parse, Self::Method),
+            map(<_ as Parser<&str>>::parse, Self::Field),
+        ))(input)
+    }
+
+    fn parse_static(input: &str) -> IResult<&str, Self> {
+        alt((
+            map(<_ as Parser<&str>>::parse, Self::StaticMethod),
+            map(<_ as Parser<&str>>::parse, Self::StaticField),
+        ))(input)
+    }
+}
+impl<V: Visitor> Visitable<V> for ClassMemberKind {
+    default fn accept(&self, v: &mut V) {
+        match self {
+            Self::Constructor(node) => v.visit(node),
+            Self::Method(node) => v.visit(node),
+            Self::Field(node) => v.visit(node),
+            Self::StaticMethod(node) => v.visit(node),
+            Self::StaticField(node) => v.visit(node),
+        }
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for ClassMemberKind {
+    default fn accept_mut(&mut self, v: &mut V) {
+        match self {
+            Self::Constructor(node) => node.accept_mut(v),
+            Self::Method(node) => node.accept_mut(v),
+            Self::Field(node) => node.accept_mut(v),
+            Self::StaticMethod(node) => node.accept_mut(v),
+            Self::StaticField(node) => node.accept_mut(v),
+        }
+    }
+}
+
+/// `constructor(a, b) { ... }`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Constructor(
+    pub KwConstructor,
+    pub WithTrivia<ParameterList<InstanceScope>>,
+    pub WithTrivia<Block<MemberScope>>,
+);
+impl<'a> Parser<&'a str> for Constructor {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(constructor, parameters, body)| Self(constructor, parameters, body),
+        )(input)
+    }
+}
+impl<V: Visitor> Visitable<V> for Constructor {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for Constructor {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+/// `name(a, b) { ... }`, with an optional return type written either before
+/// the name, `integer name()`, or after the parameters, `name() -> integer`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Method<F: Scope>(
+    pub Option<TypeFollowedById>,
+    pub WithTrivia<Identifier>,
+    pub WithTrivia<ParameterList<F>>,
+    pub Option<WithTrivia<ReturnType>>,
+    pub WithTrivia<Block<F::Function>>,
+);
+impl<F: Scope> Method<F> {
+    pub fn name(&self) -> &Identifier {
+        &self.1 .1
+    }
+}
+impl<'a, F: Scope> Parser<&'a str> for Method<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                opt(TypeFollowedById::parse_return_type),
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(ty, name, parameters, return_type, body)| {
+                Self(ty, name, parameters, return_type, body)
+            },
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for Method<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+        v.visit(&self.3);
+        v.visit(&self.4);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for Method<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+        self.3.accept_mut(v);
+        self.4.accept_mut(v);
+    }
+}
+
+/// `integer name = value;`, where the type, the value and the semicolon are
+/// all optional.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field<F: Scope>(
+    pub Option<TypeFollowedById>,
+    pub WithTrivia<Identifier>,
+    pub Option<(WithTrivia<Equal>, WithTrivia<Expression<F>>)>,
+    pub Option<WithTrivia<Semi>>,
+);
+impl<F: Scope> Field<F> {
+    pub fn name(&self) -> &Identifier {
+        &self.1 .1
+    }
+}
+impl<'a, F: Scope> Parser<&'a str> for Field<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                end_of_statement,
+            )),
+            |(ty, name, value, semi)| Self(ty, name, value, semi),
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for Field<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+        v.visit(&self.3);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for Field<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+        self.3.accept_mut(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ClassMemberKind, Modifier};
+    use crate::{
+        ast::{statements::Statements, structure::file::File},
+        error::ParseErrorKind,
+        parser::{with_version, Version},
+        test_utils::{error_kind, rejects, round_trip},
+    };
+
+    const CELL: &str = "class Cell extends Entity {
+    public static final integer SIZE = 3;
+    private x = 1
+    id
+    constructor(x) { super(x); this.x = x }
+    public integer getX() { return this.x }
+    static make() -> Cell { return new Cell(1) }
+    final toString() { for (var i in [1]) { if (i) break } }
+}";
+
+    #[test]
+    fn class_declarations() {
+        let file = round_trip::<File>(CELL);
+        let Statements::ClassDeclaration(_, class) = &file.statements[0].1 else {
+            panic!("{:?}", file.statements[0]);
+        };
+        assert_eq!(class.name().0, "Cell");
+        assert_eq!(
+            class.parent().map(|parent| parent.0.as_str()),
+            Some("Entity")
+        );
+        let members: Vec<_> = class.members().collect();
+        assert_eq!(members.len(), 7);
+        assert!(members[0].is_static() && members[0].is_final());
+        assert!(matches!(members[0].kind(), ClassMemberKind::StaticField(_)));
+        assert!(matches!(
+            members[0].modifiers().next(),
+            Some(Modifier::Public(_))
+        ));
+        assert!(
+            matches!(members[1].kind(), ClassMemberKind::Field(field) if field.name().0 == "x")
+        );
+        assert!(
+            matches!(members[2].kind(), ClassMemberKind::Field(field) if field.name().0 == "id")
+        );
+        assert!(matches!(members[3].kind(), ClassMemberKind::Constructor(_)));
+        assert!(
+            matches!(members[4].kind(), ClassMemberKind::Method(method) if method.name().0 == "getX")
+        );
+        assert!(
+            matches!(members[5].kind(), ClassMemberKind::StaticMethod(method) if method.name().0 == "make")
+        );
+        assert!(!members[6].is_static() && members[6].is_final());
+        round_trip::<File>("class A {}");
+    }
+
+    #[test]
+    fn members_end_their_line() {
+        rejects::<File>("class A { x = 1 y = 2 }");
+        round_trip::<File>("class A { x = 1; y = 2 }");
+    }
+
+    #[test]
+    fn classes_only_at_top_level() {
+        rejects::<File>("{ class A {} }");
+        rejects::<File>("function f() { class A {} }");
+    }
+
+    #[test]
+    fn member_bodies_are_functions() {
+        round_trip::<File>("class A { m() { while (a) { break } return 1 } }");
+        assert_eq!(
+            error_kind::<File>("class A { constructor() { return; break } }"),
+            ParseErrorKind::BreakOutsideLoop
+        );
+        rejects::<File>("class A { m() { global g } }");
+    }
+
+    #[test]
+    fn this_in_nested_functions() {
+        round_trip::<File>(
+            "class A { m() { var f = function() { return this.x }, g = () -> super.m() } }",
+        );
+        round_trip::<File>("class A { x = 1; y = this.x }");
+        assert_eq!(
+            error_kind::<File>("class A {} var b = this"),
+            ParseErrorKind::ThisOutsideClass
+        );
+        assert_eq!(
+            error_kind::<File>("function f() { var g = function() { return this } }"),
+            ParseErrorKind::ThisOutsideClass
+        );
+    }
+
+    #[test]
+    fn static_members_have_no_instance() {
+        assert_eq!(
+            error_kind::<File>("class A { static m() { return this.x } }"),
+            ParseErrorKind::ThisOutsideClass
+        );
+        assert_eq!(
+            error_kind::<File>("class A { static m() { var f = () -> super.m() } }"),
+            ParseErrorKind::SuperOutsideClass
+        );
+        assert_eq!(
+            error_kind::<File>("class A { x = 1; static y = this.x }"),
+            ParseErrorKind::ThisOutsideClass
+        );
+        round_trip::<File>("class A { static m() { return new A() } n() { return this } }");
+    }
+
+    #[test]
+    fn classes_need_version_2() {
+        with_version(Version::V1, || {
+            rejects::<File>("class A {}");
+            round_trip::<File>("var this = 1, super = this");
+        });
+        with_version(Version::V2, || {
+            round_trip::<File>("class A { m() { return this } }");
+        });
+    }
+}
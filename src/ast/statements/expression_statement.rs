@@ -0,0 +1,108 @@
+use nom::combinator::{not, peek};
+
+use crate::{
+    ast::{
+        expressions::Expression,
+        terminal::symbol::{LBrace, Semi},
+        trivia::with_trivia::WithTrivia,
+        utils::flags::Scope,
+    },
+    parser::{IResult, Parser},
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::end_of_statement;
+
+/// An expression evaluated for its effects, `useWeapon(enemy);` or `x = 3`.
+///
+/// Without a semicolon the statement has to end its line, see
+/// [`end_of_statement`]. A `{` at the start of a statement always opens a
+/// block and is never read as an object literal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpressionStatement<F: Scope>(pub Expression<F>, pub Option<WithTrivia<Semi>>);
+impl<'a, F: Scope> Parser<&'a str> for ExpressionStatement<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        let (input, _) = not(peek(LBrace::parse))(input)?;
+        let (input, expression) = Expression::parse(input)?;
+        let (input, semi) = end_of_statement(input)?;
+        Ok((input, Self(expression, semi)))
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for ExpressionStatement<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for ExpressionStatement<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::{statements::Statements, structure::file::File},
+        parser::{with_version, Version},
+        test_utils::{rejects, round_trip},
+    };
+
+    #[test]
+    fn expression_statements() {
+        let file = round_trip::<File>("useWeapon(enemy);\nx = 3\ny += 2 // done\nz++;w--");
+        assert_eq!(file.statements.len(), 5);
+        assert!(file
+            .statements
+            .iter()
+            .all(|statement| matches!(statement.1, Statements::Expression(_))));
+        round_trip::<File>("a = 1 /* multi\nline */ b = 2");
+        round_trip::<File>("f()");
+    }
+
+    #[test]
+    fn statements_end_their_line() {
+        for src in [
+            "a = 1 b = 2",
+            "f() g()",
+            "var a = 1 b()",
+            "integer a = 1 b = 2",
+            "global x = 1 y()",
+            "include('a') b()",
+            "function f() { return 1 2 }",
+            "while (a) { break b() }",
+            "while (a) { continue b() }",
+            "do {} while (a) b()",
+            "var a = 1 while (x) {}",
+            "a = 1 /* c */ b = 2",
+        ] {
+            rejects::<File>(src);
+        }
+        round_trip::<File>("var a = 1; b()");
+        round_trip::<File>("var a = 1\nb()");
+        round_trip::<File>("{ var a = 1 }");
+        round_trip::<File>("if (a) b = 1 else b = 2");
+    }
+
+    #[test]
+    fn do_body_ends_before_while() {
+        let file = round_trip::<File>("var x = 0\ndo x++ while (x < 3)");
+        assert!(matches!(
+            &file.statements[1].1,
+            Statements::DoWhile(node) if matches!(*node.1 .1, Statements::Expression(_))
+        ));
+        round_trip::<File>("do { x++ } while (x < 3);");
+    }
+
+    #[test]
+    fn v1_does_not_split_casts() {
+        with_version(Version::V1, || rejects::<File>("var y = v as integer"));
+    }
+
+    #[test]
+    fn braces_open_blocks() {
+        let file = round_trip::<File>("{}");
+        assert!(matches!(file.statements[0].1, Statements::Block(_)));
+    }
+}
@@ -0,0 +1,355 @@
+use nom::{
+    branch::alt,
+    combinator::{map, verify},
+    sequence::{pair, tuple},
+};
+
+use crate::{
+    ast::{
+        expressions::Expression,
+        structure::type_struct::TypeFollowedById,
+        terminal::{
+            identifier::Identifier,
+            keyword::{KwFor, KwIn, KwVar},
+            symbol::{Colon, LParen, RParen, Semi},
+        },
+        trivia::with_trivia::WithTrivia,
+        utils::flags::Scope,
+    },
+    parser::{IResult, Parser},
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::{variable_declaration::VariableDeclaration, Statements};
+
+/// `for (init; condition; update) statement`
+///
+/// The initializer ends with its own semicolon, and both the condition and
+/// the update may be left out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForStatement<F: Scope>(
+    pub KwFor,
+    pub WithTrivia<LParen>,
+    pub WithTrivia<ForInit<F>>,
+    pub Option<WithTrivia<Expression<F>>>,
+    pub WithTrivia<Semi>,
+    pub Option<WithTrivia<Expression<F>>>,
+    pub WithTrivia<RParen>,
+    pub WithTrivia<Box<Statements<F::Loop>>>,
+);
+impl<F: Scope> ForStatement<F> {
+    /// The variables declared by the initializer, bound in the loop.
+    pub fn variables(&self) -> impl Iterator<Item = &Identifier> {
+        let declaration = match &self.2 .1 {
+            ForInit::Declaration(declaration) => Some(declaration.assignments()),
+            ForInit::Expression(..) | ForInit::Empty(_) => None,
+        };
+        declaration
+            .into_iter()
+            .flat_map(|assignments| assignments.names())
+    }
+}
+impl<'a, F: Scope> Parser<&'a str> for ForStatement<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(kw_for, lparen, init, condition, semi, update, rparen, body)| {
+                Self(kw_for, lparen, init, condition, semi, update, rparen, body)
+            },
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for ForStatement<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+        v.visit(&self.3);
+        v.visit(&self.4);
+        v.visit(&self.5);
+        v.visit(&self.6);
+        v.visit(&self.7);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for ForStatement<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+        self.3.accept_mut(v);
+        self.4.accept_mut(v);
+        self.5.accept_mut(v);
+        self.6.accept_mut(v);
+        self.7.accept_mut(v);
+    }
+}
+
+/// The initializer of a [`ForStatement`], up to and including its semicolon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ForInit<F: Scope> {
+    /// `var i = 0;`, whose semicolon is the declaration's own.
+    Declaration(VariableDeclaration<F>),
+    Expression(Expression<F>, WithTrivia<Semi>),
+    Empty(Semi),
+}
+impl<'a, F: Scope> Parser<&'a str> for ForInit<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        alt((
+            map(
+                verify(
+                    <VariableDeclaration<F> as Parser<&'a str>>::parse,
+                    |declaration| declaration.semi().is_some(),
+                ),
+                Self::Declaration,
+            ),
+            map(
+                pair(<_ as Parser<&'a str>>::parse, <_ as Parser<&'a str>>::parse),
+                |(expression, semi)| Self::Expression(expression, semi),
+            ),
+            map(<_ as Parser<&'a str>>::parse, Self::Empty),
+        ))(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for ForInit<F> {
+    default fn accept(&self, v: &mut V) {
+        match self {
+            Self::Declaration(node) => v.visit(node),
+            Self::Expression(expression, semi) => {
+                v.visit(expression);
+                v.visit(semi);
+            }
+            Self::Empty(node) => v.visit(node),
+        }
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for ForInit<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        match self {
+            Self::Declaration(node) => node.accept_mut(v),
+            Self::Expression(expression, semi) => {
+                expression.accept_mut(v);
+                semi.accept_mut(v);
+            }
+            Self::Empty(node) => node.accept_mut(v),
+        }
+    }
+}
+
+/// `for (var value in container) statement`, or with the key as well
+/// `for (var key : var value in container) statement`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForInStatement<F: Scope>(
+    pub KwFor,
+    pub WithTrivia<LParen>,
+    pub WithTrivia<ForVariable>,
+    pub Option<(WithTrivia<Colon>, WithTrivia<ForVariable>)>,
+    pub WithTrivia<KwIn>,
+    pub WithTrivia<Expression<F>>,
+    pub WithTrivia<RParen>,
+    pub WithTrivia<Box<Statements<F::Loop>>>,
+);
+impl<F: Scope> ForInStatement<F> {
+    /// The variable bound to each key, if the loop names one.
+    pub fn key(&self) -> Option<&ForVariable> {
+        self.3.as_ref().map(|_| &self.2 .1)
+    }
+
+    /// The variable bound to each value.
+    pub fn value(&self) -> &ForVariable {
+        match &self.3 {
+            Some((_, value)) => &value.1,
+            None => &self.2 .1,
+        }
+    }
+
+    pub fn container(&self) -> &Expression<F> {
+        &self.5 .1
+    }
+}
+impl<'a, F: Scope> Parser<&'a str> for ForInStatement<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(kw_for, lparen, first, value, kw_in, container, rparen, body)| {
+                Self(kw_for, lparen, first, value, kw_in, container, rparen, body)
+            },
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for ForInStatement<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+        v.visit(&self.3);
+        v.visit(&self.4);
+        v.visit(&self.5);
+        v.visit(&self.6);
+        v.visit(&self.7);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for ForInStatement<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+        self.3.accept_mut(v);
+        self.4.accept_mut(v);
+        self.5.accept_mut(v);
+        self.6.accept_mut(v);
+        self.7.accept_mut(v);
+    }
+}
+
+/// A loop variable of a [`ForInStatement`]: `var x`, `integer x`, or an
+/// existing variable `x`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForVariable(pub Option<ForDeclarator>, pub WithTrivia<Identifier>);
+impl ForVariable {
+    pub fn name(&self) -> &Identifier {
+        &self.1 .1
+    }
+
+    /// Whether the loop declares the variable rather than reusing one.
+    pub fn is_declaration(&self) -> bool {
+        self.0.is_some()
+    }
+}
+impl<'a> Parser<&'a str> for ForVariable {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            pair(<_ as Parser<&'a str>>::parse, <_ as Parser<&'a str>>::parse),
+            |(declarator, name)| Self(declarator, name),
+        )(input)
+    }
+}
+impl<V: Visitor> Visitable<V> for ForVariable {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for ForVariable {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ForDeclarator {
+    Var(KwVar),
+    Type(TypeFollowedById),
+}
+impl<'a> Parser<&'a str> for ForDeclarator {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        alt((
+            map(<_ as Parser<&'a str>>::parse, Self::Var),
+            map(<_ as Parser<&'a str>>::parse, Self::Type),
+        ))(input)
+    }
+}
+impl<V: Visitor> Visitable<V> for ForDeclarator {
+    default fn accept(&self, v: &mut V) {
+        match self {
+            Self::Var(node) => v.visit(node),
+            Self::Type(node) => v.visit(node),
+        }
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for ForDeclarator {
+    default fn accept_mut(&mut self, v: &mut V) {
+        match self {
+            Self::Var(node) => node.accept_mut(v),
+            Self::Type(node) => node.accept_mut(v),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ForInit;
+    use crate::{
+        ast::{statements::Statements, structure::file::File},
+        test_utils::{rejects, round_trip, write},
+    };
+
+    #[test]
+    fn c_style_loops() {
+        let file = round_trip::<File>(
+            "for (var i = 0, j = 1; i < n; i++) { x += i }\nfor (;;) {}\nfor (i = 0 ; ; i++) f()",
+        );
+        let Statements::For(node) = &file.statements[0].1 else {
+            panic!("{:?}", file.statements[0]);
+        };
+        let names: Vec<_> = node.variables().map(|name| name.0.as_str()).collect();
+        assert_eq!(names, ["i", "j"]);
+        let Statements::For(node) = &file.statements[1].1 else {
+            panic!("{:?}", file.statements[1]);
+        };
+        assert!(matches!(node.2 .1, ForInit::Empty(_)));
+        assert_eq!(node.variables().count(), 0);
+        let Statements::For(node) = &file.statements[2].1 else {
+            panic!("{:?}", file.statements[2]);
+        };
+        assert!(matches!(node.2 .1, ForInit::Expression(..)));
+        assert_eq!(node.variables().count(), 0);
+        rejects::<File>("for (var i = 0 i < 1; i++) {}");
+        rejects::<File>("for (i < 1; i++) {}");
+    }
+
+    #[test]
+    fn for_in_loops() {
+        let file = round_trip::<File>(
+            "for (var x in arr) print(x)\nfor ( var k : var v in [1: 2] ) {}\nfor (k : v in m) {}\nfor (integer x in arr) {}",
+        );
+        let Statements::ForIn(node) = &file.statements[0].1 else {
+            panic!("{:?}", file.statements[0]);
+        };
+        assert!(node.key().is_none());
+        assert_eq!(node.value().name().0, "x");
+        assert!(node.value().is_declaration());
+        assert_eq!(write(node.container()), "arr");
+        let Statements::ForIn(node) = &file.statements[1].1 else {
+            panic!("{:?}", file.statements[1]);
+        };
+        assert_eq!(node.key().unwrap().name().0, "k");
+        assert_eq!(node.value().name().0, "v");
+        let Statements::ForIn(node) = &file.statements[2].1 else {
+            panic!("{:?}", file.statements[2]);
+        };
+        assert!(!node.key().unwrap().is_declaration());
+        assert!(!node.value().is_declaration());
+        let Statements::ForIn(node) = &file.statements[3].1 else {
+            panic!("{:?}", file.statements[3]);
+        };
+        assert!(node.value().is_declaration());
+        rejects::<File>("for (var x in) {}");
+        rejects::<File>("for (var k : in m) {}");
+    }
+
+    #[test]
+    fn bodies_are_loops() {
+        round_trip::<File>("for (;;) { if (a) break; continue }");
+        round_trip::<File>("for (var x in y) break");
+        rejects::<File>("for (;;) { global g }");
+    }
+}
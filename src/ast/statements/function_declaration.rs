@@ -0,0 +1,144 @@
+use nom::{combinator::map, sequence::tuple};
+
+use crate::{
+    ast::{
+        structure::{parameter::ParameterList, type_struct::Type},
+        terminal::{identifier::Identifier, keyword::KwFunction, symbol::MinusRAngle},
+        trivia::with_trivia::WithTrivia,
+        utils::flags::Scope,
+    },
+    parser::{IResult, Parser},
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::block::Block;
+
+/// `function name(a, integer b, c = 3) -> integer { ... }`
+///
+/// Only allowed at the top level, see [`super::Statements::FunctionDeclaration`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionDeclaration<F: Scope>(
+    pub KwFunction,
+    pub WithTrivia<Identifier>,
+    pub WithTrivia<ParameterList<F>>,
+    pub Option<WithTrivia<ReturnType>>,
+    pub WithTrivia<Block<F::Function>>,
+);
+impl<F: Scope> FunctionDeclaration<F> {
+    pub fn name(&self) -> &Identifier {
+        &self.1 .1
+    }
+
+    pub fn return_type(&self) -> Option<&Type> {
+        self.3.as_ref().map(|return_type| &return_type.1 .1 .1)
+    }
+}
+impl<'a, F: Scope> Parser<&'a str> for FunctionDeclaration<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(function, name, parameters, return_type, body)| {
+                Self(function, name, parameters, return_type, body)
+            },
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for FunctionDeclaration<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+        v.visit(&self.3);
+        v.visit(&self.4);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for FunctionDeclaration<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+        self.3.accept_mut(v);
+        self.4.accept_mut(v);
+    }
+}
+
+/// `-> integer`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReturnType(pub MinusRAngle, pub WithTrivia<Type>);
+impl<'a> Parser<&'a str> for ReturnType {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((<_ as Parser<&'a str>>::parse, <_ as Parser<&'a str>>::parse)),
+            |(arrow, ty)| Self(arrow, ty),
+        )(input)
+    }
+}
+impl<V: Visitor> Visitable<V> for ReturnType {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for ReturnType {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::{statements::Statements, structure::file::File},
+        test_utils::{grouped, rejects, round_trip, write},
+    };
+
+    #[test]
+    fn function_declarations() {
+        let file = round_trip::<File>(
+            "function name(a, integer b, c = 3) -> integer { return a + b * c }\nfunction f() {}",
+        );
+        let Statements::FunctionDeclaration(_, function) = &file.statements[0].1 else {
+            panic!("{:?}", file.statements[0]);
+        };
+        assert_eq!(function.name().0, "name");
+        assert_eq!(
+            function.return_type().map(write).as_deref(),
+            Some("integer")
+        );
+        let parameters: Vec<_> = function.2 .1 .1 .0.iter().map(|p| &p.1).collect();
+        let names: Vec<_> = parameters.iter().map(|p| p.name().0.as_str()).collect();
+        assert_eq!(names, ["a", "b", "c"]);
+        assert!(parameters[1].1.is_some());
+        assert_eq!(
+            parameters[2].default_value().map(write).as_deref(),
+            Some("3")
+        );
+        let Statements::FunctionDeclaration(_, function) = &file.statements[1].1 else {
+            panic!("{:?}", file.statements[1]);
+        };
+        assert!(function.return_type().is_none());
+        round_trip::<File>("function g(x) { while (x) { if (x) return x; break } }");
+        rejects::<File>("function f() -> {}");
+    }
+
+    #[test]
+    fn only_at_top_level() {
+        rejects::<File>("{ function f() {} }");
+        rejects::<File>("function f() { function g() {} }");
+        rejects::<File>("while (a) function f() {}");
+        rejects::<File>("if (a) { function f() {} }");
+    }
+
+    #[test]
+    fn lambda_parameters_have_no_default() {
+        assert_eq!(grouped("x = y -> y"), "(x = y -> y)");
+        round_trip::<File>("var l = x = y -> y");
+    }
+}
@@ -1,4 +1,4 @@
-use nom::{combinator::map, sequence::tuple, IResult};
+use nom::{combinator::map, sequence::tuple};
 
 use crate::{
     ast::{
@@ -6,32 +6,35 @@ use crate::{
         structure::type_struct::TypeFollowedById,
         terminal::{keyword::KwGlobal, symbol::Semi},
         trivia::with_trivia::WithTrivia,
+        utils::flags::Scope,
     },
-    parser::Parser,
+    parser::{IResult, Parser},
     visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
 };
 
+use super::end_of_statement;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct GlobalDeclaration(
+pub struct GlobalDeclaration<F: Scope>(
     pub WithTrivia<KwGlobal>,
     pub Option<WithTrivia<TypeFollowedById>>,
-    pub AssignmentList,
+    pub AssignmentList<F>,
     pub Option<WithTrivia<Semi>>,
 );
-impl<'a> Parser<&'a str> for GlobalDeclaration {
+impl<'a, F: Scope> Parser<&'a str> for GlobalDeclaration<F> {
     fn parse(input: &'a str) -> IResult<&'a str, Self> {
         map(
             tuple((
                 <_ as Parser<&'a str>>::parse,
                 <_ as Parser<&'a str>>::parse,
                 <_ as Parser<&'a str>>::parse,
-                <_ as Parser<&'a str>>::parse,
+                end_of_statement,
             )),
             |(global, ty, assignments, semi)| Self(global, ty, assignments, semi),
         )(input)
     }
 }
-impl<V: Visitor> Visitable<V> for GlobalDeclaration {
+impl<V: Visitor, F: Scope> Visitable<V> for GlobalDeclaration<F> {
     default fn accept(&self, v: &mut V) {
         v.visit(&self.0);
         v.visit(&self.1);
@@ -39,7 +42,7 @@ impl<V: Visitor> Visitable<V> for GlobalDeclaration {
         v.visit(&self.3);
     }
 }
-impl<V: VisitorMut> VisitableMut<V> for GlobalDeclaration {
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for GlobalDeclaration<F> {
     default fn accept_mut(&mut self, v: &mut V) {
         self.0.accept_mut(v);
         self.1.accept_mut(v);
@@ -0,0 +1,220 @@
+use nom::{combinator::map, sequence::tuple};
+
+use crate::{
+    ast::{
+        expressions::{parenthesized::Parenthesized, Expression},
+        terminal::keyword::{KwElse, KwIf},
+        trivia::with_trivia::WithTrivia,
+        utils::flags::Scope,
+    },
+    parser::{IResult, Parser},
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::Statements;
+
+/// `if (condition) statement`, followed by any number of `else if` branches
+/// and an optional final `else`.
+///
+/// The `else if` branches are kept in a flat list rather than nested in each
+/// other's `else`, see [`IfStatement::branches`] to walk them in order. Each
+/// body is a single statement, a block being one of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IfStatement<F: Scope>(
+    pub KwIf,
+    pub WithTrivia<Parenthesized<F>>,
+    pub WithTrivia<Box<Statements<F::Block>>>,
+    pub Vec<WithTrivia<ElseIf<F>>>,
+    pub Option<WithTrivia<Else<F>>>,
+);
+impl<F: Scope> IfStatement<F> {
+    /// Every branch in source order, the final `else` having no condition.
+    pub fn branches(&self) -> impl Iterator<Item = IfBranch<'_, F>> {
+        let first = IfBranch {
+            condition: Some(self.1 .1.inner()),
+            body: &self.2 .1,
+        };
+        let else_ifs = self.3.iter().map(|WithTrivia(_, branch)| IfBranch {
+            condition: Some(branch.2 .1.inner()),
+            body: &branch.3 .1,
+        });
+        let otherwise = self.4.iter().map(|WithTrivia(_, branch)| IfBranch {
+            condition: None,
+            body: &branch.1 .1,
+        });
+        std::iter::once(first).chain(else_ifs).chain(otherwise)
+    }
+}
+impl<'a, F: Scope> Parser<&'a str> for IfStatement<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(kw_if, condition, body, else_ifs, otherwise)| {
+                Self(kw_if, condition, body, else_ifs, otherwise)
+            },
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for IfStatement<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+        v.visit(&self.3);
+        v.visit(&self.4);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for IfStatement<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+        self.3.accept_mut(v);
+        self.4.accept_mut(v);
+    }
+}
+
+/// One branch of an [`IfStatement`].
+#[derive(Debug, Clone, Copy)]
+pub struct IfBranch<'a, F: Scope> {
+    pub condition: Option<&'a Expression<F>>,
+    pub body: &'a Statements<F::Block>,
+}
+
+/// `else if (condition) statement`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElseIf<F: Scope>(
+    pub KwElse,
+    pub WithTrivia<KwIf>,
+    pub WithTrivia<Parenthesized<F>>,
+    pub WithTrivia<Box<Statements<F::Block>>>,
+);
+impl<'a, F: Scope> Parser<&'a str> for ElseIf<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(kw_else, kw_if, condition, body)| Self(kw_else, kw_if, condition, body),
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for ElseIf<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+        v.visit(&self.3);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for ElseIf<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+        self.3.accept_mut(v);
+    }
+}
+
+/// `else statement`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Else<F: Scope>(pub KwElse, pub WithTrivia<Box<Statements<F::Block>>>);
+impl<'a, F: Scope> Parser<&'a str> for Else<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((<_ as Parser<&'a str>>::parse, <_ as Parser<&'a str>>::parse)),
+            |(kw_else, body)| Self(kw_else, body),
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for Else<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for Else<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::{statements::Statements, structure::file::File},
+        test_utils::{rejects, round_trip, write},
+    };
+
+    #[test]
+    fn if_statements() {
+        round_trip::<File>("if (a) b()");
+        round_trip::<File>("if (a) { var x } else { y() }");
+        let file = round_trip::<File>("if (a) b() else if (c) { d = 1 }\nelse if(e)f;else { }");
+        let [ref statement] = file.statements[..] else {
+            panic!("{:?}", file.statements);
+        };
+        let Statements::If(node) = &statement.1 else {
+            panic!("{statement:?}");
+        };
+        assert!(matches!(*node.2 .1, Statements::Expression(_)));
+        assert_eq!(node.3.len(), 2);
+        assert!(matches!(node.3[0].1 .3 .1.as_ref(), Statements::Block(_)));
+        assert!(matches!(
+            node.4.as_ref().map(|e| e.1 .1 .1.as_ref()),
+            Some(Statements::Block(_))
+        ));
+        round_trip::<File>("if (a)\n    b = 1\nelse\n    b = 2");
+        rejects::<File>("if a b()");
+        rejects::<File>("if (a) else b()");
+    }
+
+    #[test]
+    fn branches_in_order() {
+        let file = round_trip::<File>("if (a) b else if (c) d else if (e) f else g");
+        let Statements::If(node) = &file.statements[0].1 else {
+            panic!("{:?}", file.statements[0]);
+        };
+        assert_eq!(node.3.len(), 2);
+        let branches: Vec<_> = node
+            .branches()
+            .map(|branch| (branch.condition.map(write), write(branch.body)))
+            .collect();
+        assert_eq!(
+            branches,
+            [
+                (Some("a".to_string()), "b".to_string()),
+                (Some("c".to_string()), "d".to_string()),
+                (Some("e".to_string()), "f".to_string()),
+                (None, "g".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn else_binds_to_nearest_if() {
+        let file = round_trip::<File>("if (x) if (y) z else w");
+        let Statements::If(outer) = &file.statements[0].1 else {
+            panic!("{:?}", file.statements[0]);
+        };
+        assert!(outer.4.is_none());
+        assert!(matches!(*outer.2 .1, Statements::If(ref inner) if inner.4.is_some()));
+    }
+
+    #[test]
+    fn branches_are_nested_scopes() {
+        rejects::<File>("if (a) { global g }");
+        rejects::<File>("if (a) global g");
+        rejects::<File>("if (a) {} else global g");
+    }
+}
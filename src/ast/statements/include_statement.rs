@@ -0,0 +1,97 @@
+use nom::{combinator::map, sequence::tuple};
+
+use crate::{
+    ast::{
+        terminal::{
+            keyword::KwInclude,
+            string::StringLiteral,
+            symbol::{LParen, RParen, Semi},
+        },
+        trivia::with_trivia::WithTrivia,
+    },
+    parser::{IResult, Parser},
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::end_of_statement;
+
+/// `include("path/to/ai");`
+///
+/// Only allowed at the top level. The included file is not loaded by the
+/// parser, see [`crate::resolver::Resolver`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncludeStatement(
+    pub KwInclude,
+    pub WithTrivia<LParen>,
+    pub WithTrivia<StringLiteral>,
+    pub WithTrivia<RParen>,
+    pub Option<WithTrivia<Semi>>,
+);
+impl IncludeStatement {
+    /// The included path, with escape sequences decoded.
+    pub fn path(&self) -> &str {
+        &self.2 .1.value
+    }
+}
+impl<'a> Parser<&'a str> for IncludeStatement {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                end_of_statement,
+            )),
+            |(include, lparen, path, rparen, semi)| Self(include, lparen, path, rparen, semi),
+        )(input)
+    }
+}
+impl<V: Visitor> Visitable<V> for IncludeStatement {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+        v.visit(&self.3);
+        v.visit(&self.4);
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for IncludeStatement {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+        self.3.accept_mut(v);
+        self.4.accept_mut(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::{statements::Statements, structure::file::File},
+        test_utils::{rejects, round_trip},
+    };
+
+    #[test]
+    fn includes() {
+        let file = round_trip::<File>("include(\"a\");\ninclude ( 'dir/b\\u00e9' )\n");
+        let paths: Vec<_> = file
+            .statements
+            .iter()
+            .map(|statement| match &statement.1 {
+                Statements::Include(_, include) => include.path(),
+                other => panic!("{other:?}"),
+            })
+            .collect();
+        assert_eq!(paths, ["a", "dir/bé"]);
+        rejects::<File>("include(a)");
+        rejects::<File>("include()");
+    }
+
+    #[test]
+    fn only_at_top_level() {
+        rejects::<File>("{ include(\"a\") }");
+        rejects::<File>("function f() { include(\"a\") }");
+    }
+}
@@ -0,0 +1,193 @@
+use nom::combinator::opt;
+
+use crate::{
+    ast::{
+        expressions::Expression,
+        terminal::{
+            keyword::{KwBreak, KwContinue, KwReturn},
+            symbol::Semi,
+        },
+        trivia::with_trivia::WithTrivia,
+        utils::flags::{FunctionFlag, HasFlag, LoopFlag, Scope},
+    },
+    error::ParseErrorKind,
+    parser::{IResult, Parser},
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::end_of_statement;
+
+/// `break;`, only allowed in the body of a loop.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BreakStatement<F: Scope>(
+    pub KwBreak,
+    pub HasFlag<LoopFlag, F>,
+    pub Option<WithTrivia<Semi>>,
+);
+impl<'a, F: Scope> Parser<&'a str> for BreakStatement<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        let (rest, kw_break) = KwBreak::parse(input)?;
+        let flag = HasFlag::require(input, ParseErrorKind::BreakOutsideLoop)?;
+        let (rest, semi) = end_of_statement(rest)?;
+        Ok((rest, Self(kw_break, flag, semi)))
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for BreakStatement<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for BreakStatement<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+/// `continue;`, only allowed in the body of a loop.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContinueStatement<F: Scope>(
+    pub KwContinue,
+    pub HasFlag<LoopFlag, F>,
+    pub Option<WithTrivia<Semi>>,
+);
+impl<'a, F: Scope> Parser<&'a str> for ContinueStatement<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        let (rest, kw_continue) = KwContinue::parse(input)?;
+        let flag = HasFlag::require(input, ParseErrorKind::ContinueOutsideLoop)?;
+        let (rest, semi) = end_of_statement(rest)?;
+        Ok((rest, Self(kw_continue, flag, semi)))
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for ContinueStatement<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for ContinueStatement<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+/// `return value;`, with an optional value, only allowed in the body of a
+/// function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReturnStatement<F: Scope>(
+    pub KwReturn,
+    pub HasFlag<FunctionFlag, F>,
+    pub Option<WithTrivia<Expression<F>>>,
+    pub Option<WithTrivia<Semi>>,
+);
+impl<F: Scope> ReturnStatement<F> {
+    pub fn value(&self) -> Option<&Expression<F>> {
+        self.2.as_ref().map(|value| &value.1)
+    }
+}
+impl<'a, F: Scope> Parser<&'a str> for ReturnStatement<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        let (rest, kw_return) = KwReturn::parse(input)?;
+        let flag = HasFlag::require(input, ParseErrorKind::ReturnOutsideFunction)?;
+        let (rest, value) = opt(<_ as Parser<&'a str>>::parse)(rest)?;
+        let (rest, semi) = end_of_statement(rest)?;
+        Ok((rest, Self(kw_return, flag, value, semi)))
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for ReturnStatement<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+        v.visit(&self.3);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for ReturnStatement<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+        self.3.accept_mut(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::{statements::Statements, structure::file::File},
+        error::ParseErrorKind,
+        test_utils::{error_kind, round_trip, write},
+    };
+
+    #[test]
+    fn jumps_in_context() {
+        let file = round_trip::<File>("while (a) { if (b) break; continue }");
+        let Statements::While(node) = &file.statements[0].1 else {
+            panic!("{:?}", file.statements[0]);
+        };
+        assert!(matches!(
+            node.2 .1.as_ref(),
+            Statements::Block(block)
+                if matches!(block.1[..], [ref first, ref second]
+                    if matches!(first.1, Statements::If(_)) && matches!(second.1, Statements::Continue(_)))
+        ));
+        round_trip::<File>("for (;;) { do { break } while (1) }");
+        round_trip::<File>(
+            "var f = function() { return 1; }, g = function() { while (x) { return } }",
+        );
+        round_trip::<File>("var h = x -> { return x }");
+        round_trip::<File>(
+            "var f = function() { for (x in y) { var g = function() { return; } } }",
+        );
+    }
+
+    #[test]
+    fn return_values() {
+        let file = round_trip::<File>("function f() { return a + 1\nreturn }");
+        let Statements::FunctionDeclaration(_, function) = &file.statements[0].1 else {
+            panic!("{:?}", file.statements[0]);
+        };
+        let values: Vec<_> = function
+            .4
+             .1
+             .1
+            .iter()
+            .map(|statement| match &statement.1 {
+                Statements::Return(node) => node.value().map(write),
+                other => panic!("{other:?}"),
+            })
+            .collect();
+        assert_eq!(values, [Some("a + 1".to_string()), None]);
+    }
+
+    #[test]
+    fn jumps_out_of_context() {
+        for (src, kind) in [
+            ("break", ParseErrorKind::BreakOutsideLoop),
+            ("{ continue; }", ParseErrorKind::ContinueOutsideLoop),
+            ("if (a) continue", ParseErrorKind::ContinueOutsideLoop),
+            ("return 1", ParseErrorKind::ReturnOutsideFunction),
+            (
+                "while (a) { return }",
+                ParseErrorKind::ReturnOutsideFunction,
+            ),
+            ("function f() { break }", ParseErrorKind::BreakOutsideLoop),
+            (
+                "while (a) { var f = function() { break } }",
+                ParseErrorKind::BreakOutsideLoop,
+            ),
+            (
+                "for (;;) { var f = () -> { continue } }",
+                ParseErrorKind::ContinueOutsideLoop,
+            ),
+        ] {
+            assert_eq!(error_kind::<File>(src), kind, "{src}");
+        }
+    }
+}
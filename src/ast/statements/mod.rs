@@ -1,63 +1,251 @@
+use block::Block;
+use class_declaration::ClassDeclaration;
+use expression_statement::ExpressionStatement;
+use for_statement::{ForInStatement, ForStatement};
+use function_declaration::FunctionDeclaration;
 use global_declaration::GlobalDeclaration;
-use nom::{branch::alt, combinator::map, sequence::pair, IResult};
+use if_statement::IfStatement;
+use include_statement::IncludeStatement;
+use jump_statement::{BreakStatement, ContinueStatement, ReturnStatement};
+use nom::{
+    branch::alt,
+    combinator::{map, opt},
+    error::{ErrorKind, ParseError as _},
+    sequence::pair,
+};
 use variable_declaration::VariableDeclaration;
+use while_statement::{DoWhileStatement, WhileStatement};
 
 use crate::{
-    parser::Parser,
+    ast::{
+        terminal::{keyword::KwElse, symbol::Semi},
+        trivia::{trivia::Trivia, with_trivia::WithTrivia},
+        utils::repeat::Repeat0,
+    },
+    error::ParseError,
+    parser::{IResult, Parser},
     visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
 };
 
-use super::utils::flags::{Flag, HasFlag, WithFlag};
+use super::utils::flags::{Cons, Flag, HasFlag, Scope};
 
 pub mod assignment;
+pub mod block;
+pub mod class_declaration;
+pub mod expression_statement;
+pub mod for_statement;
+pub mod function_declaration;
 pub mod global_declaration;
+pub mod if_statement;
+pub mod include_statement;
+pub mod jump_statement;
 pub mod variable_declaration;
+pub mod while_statement;
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct GlobalFlag;
 impl Flag for GlobalFlag {
     const FLAG: bool = true;
+    const ID: u8 = 0;
 }
-impl WithFlag<GlobalFlag> for GlobalFlag {
-    const HAS: bool = true;
+impl<T: Scope> Scope for Cons<GlobalFlag, T> {
+    type Block = T::Block;
+    type Loop = T::Loop;
+    type Function = T::Function;
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum Statements<F: WithFlag<GlobalFlag>> {
-    GlobalDeclaration(HasFlag<GlobalFlag, F>, GlobalDeclaration),
-    VariableDeclaration(VariableDeclaration),
+pub enum Statements<F: Scope> {
+    GlobalDeclaration(HasFlag<GlobalFlag, F>, GlobalDeclaration<F>),
+    /// Named functions are only declared at the top level.
+    FunctionDeclaration(HasFlag<GlobalFlag, F>, FunctionDeclaration<F>),
+    /// Classes are only declared at the top level.
+    ClassDeclaration(HasFlag<GlobalFlag, F>, ClassDeclaration),
+    /// Files are only included at the top level.
+    Include(HasFlag<GlobalFlag, F>, IncludeStatement),
+    VariableDeclaration(VariableDeclaration<F>),
+    Block(Block<F>),
+    If(IfStatement<F>),
+    While(WhileStatement<F>),
+    DoWhile(DoWhileStatement<F>),
+    For(ForStatement<F>),
+    ForIn(ForInStatement<F>),
+    Break(BreakStatement<F>),
+    Continue(ContinueStatement<F>),
+    Return(ReturnStatement<F>),
+    Expression(ExpressionStatement<F>),
+    /// A stray `;`, as in `a;;`.
+    Empty(Semi),
 }
 
-impl<'a, F: WithFlag<GlobalFlag>> Parser<&'a str> for Statements<F> {
+impl<'a, F: Scope> Parser<&'a str> for Statements<F> {
     fn parse(input: &'a str) -> IResult<&'a str, Self> {
         alt((
             map(
                 pair(<_ as Parser<&'a str>>::parse, <_ as Parser<&'a str>>::parse),
                 |(first, last)| Self::GlobalDeclaration(first, last),
             ),
+            map(
+                pair(<_ as Parser<&'a str>>::parse, <_ as Parser<&'a str>>::parse),
+                |(first, last)| Self::FunctionDeclaration(first, last),
+            ),
+            map(
+                pair(<_ as Parser<&'a str>>::parse, <_ as Parser<&'a str>>::parse),
+                |(first, last)| Self::ClassDeclaration(first, last),
+            ),
+            map(
+                pair(<_ as Parser<&'a str>>::parse, <_ as Parser<&'a str>>::parse),
+                |(first, last)| Self::Include(first, last),
+            ),
             map(<_ as Parser<&'a str>>::parse, Self::VariableDeclaration),
+            map(<_ as Parser<&'a str>>::parse, Self::Block),
+            map(<_ as Parser<&'a str>>::parse, Self::If),
+            map(<_ as Parser<&'a str>>::parse, Self::While),
+            map(<_ as Parser<&'a str>>::parse, Self::DoWhile),
+            map(<_ as Parser<&'a str>>::parse, Self::ForIn),
+            map(<_ as Parser<&'a str>>::parse, Self::For),
+            map(<_ as Parser<&'a str>>::parse, Self::Break),
+            map(<_ as Parser<&'a str>>::parse, Self::Continue),
+            map(<_ as Parser<&'a str>>::parse, Self::Return),
+            map(<_ as Parser<&'a str>>::parse, Self::Expression),
+            map(<_ as Parser<&'a str>>::parse, Self::Empty),
         ))(input)
     }
 }
-impl<V: Visitor, F: WithFlag<GlobalFlag>> Visitable<V> for Statements<F> {
+impl<V: Visitor, F: Scope> Visitable<V> for Statements<F> {
     default fn accept(&self, v: &mut V) {
         match self {
             Self::GlobalDeclaration(flag, node) => {
                 flag.accept(v);
                 node.accept(v);
             }
+            Self::FunctionDeclaration(flag, node) => {
+                flag.accept(v);
+                node.accept(v);
+            }
+            Self::ClassDeclaration(flag, node) => {
+                flag.accept(v);
+                node.accept(v);
+            }
+            Self::Include(flag, node) => {
+                flag.accept(v);
+                node.accept(v);
+            }
             Self::VariableDeclaration(node) => node.accept(v),
+            Self::Block(node) => node.accept(v),
+            Self::If(node) => node.accept(v),
+            Self::While(node) => node.accept(v),
+            Self::DoWhile(node) => node.accept(v),
+            Self::For(node) => node.accept(v),
+            Self::ForIn(node) => node.accept(v),
+            Self::Break(node) => node.accept(v),
+            Self::Continue(node) => node.accept(v),
+            Self::Return(node) => node.accept(v),
+            Self::Expression(node) => node.accept(v),
+            Self::Empty(node) => v.visit(node),
         }
     }
 }
-impl<V: VisitorMut, F: WithFlag<GlobalFlag>> VisitableMut<V> for Statements<F> {
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for Statements<F> {
     default fn accept_mut(&mut self, v: &mut V) {
         match self {
             Self::GlobalDeclaration(flag, node) => {
                 flag.accept_mut(v);
                 node.accept_mut(v);
             }
+            Self::FunctionDeclaration(flag, node) => {
+                flag.accept_mut(v);
+                node.accept_mut(v);
+            }
+            Self::ClassDeclaration(flag, node) => {
+                flag.accept_mut(v);
+                node.accept_mut(v);
+            }
+            Self::Include(flag, node) => {
+                flag.accept_mut(v);
+                node.accept_mut(v);
+            }
             Self::VariableDeclaration(node) => node.accept_mut(v),
+            Self::Block(node) => node.accept_mut(v),
+            Self::If(node) => node.accept_mut(v),
+            Self::While(node) => node.accept_mut(v),
+            Self::DoWhile(node) => node.accept_mut(v),
+            Self::For(node) => node.accept_mut(v),
+            Self::ForIn(node) => node.accept_mut(v),
+            Self::Break(node) => node.accept_mut(v),
+            Self::Continue(node) => node.accept_mut(v),
+            Self::Return(node) => node.accept_mut(v),
+            Self::Expression(node) => node.accept_mut(v),
+            Self::Empty(node) => node.accept_mut(v),
         }
     }
 }
+
+/// Parses the optional semicolon ending a simple statement.
+///
+/// Without a semicolon the statement has to end its line: what follows must
+/// be on a later line, a closing `}`, an `else` or the end of the input, so
+/// `a = 1 b = 2` is rejected. The body of a `do` loop may also end before its
+/// `while`, see [`DoWhileStatement`].
+pub(crate) fn end_of_statement(input: &str) -> IResult<&str, Option<WithTrivia<Semi>>> {
+    let (input, semi) = opt(<WithTrivia<Semi> as Parser<&str>>::parse)(input)?;
+    if semi.is_none() && !ends_line(input)? {
+        return Err(nom::Err::Error(ParseError::from_error_kind(
+            input,
+            ErrorKind::Verify,
+        )));
+    }
+    Ok((input, semi))
+}
+
+/// Whether a statement may end before `input` without a semicolon.
+fn ends_line(input: &str) -> Result<bool, nom::Err<ParseError<&str>>> {
+    let (rest, trivia) = Repeat0::<Trivia>::parse(input)?;
+    let trivia: Vec<Trivia> = trivia.into();
+    Ok(trivia.iter().any(Trivia::has_newline)
+        || rest.is_empty()
+        || rest.starts_with('}')
+        || KwElse::parse(rest).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Statements;
+    use crate::{ast::structure::file::File, test_utils::round_trip};
+
+    #[test]
+    fn empty_statements() {
+        let file = round_trip::<File>(";");
+        assert!(
+            matches!(file.statements[..], [ref only] if matches!(only.1, Statements::Empty(_)))
+        );
+        let file = round_trip::<File>("var a = 1;;\n ; /* c */ ;\n");
+        let empty = file
+            .statements
+            .iter()
+            .filter(|statement| matches!(statement.1, Statements::Empty(_)))
+            .count();
+        assert_eq!(empty, 3);
+        assert_eq!(file.statements.len(), 4);
+    }
+
+    #[test]
+    fn empty_statements_in_bodies() {
+        round_trip::<File>("function f() { return 1;; }");
+        let file = round_trip::<File>("while (x) ;\nif (a) ; else { ; }");
+        assert!(matches!(
+            &file.statements[0].1,
+            Statements::While(node) if matches!(*node.2 .1, Statements::Empty(_))
+        ));
+        let file = round_trip::<File>("{ ; }\nfor (;;) ;");
+        assert!(matches!(
+            &file.statements[0].1,
+            Statements::Block(block) if matches!(block.1[..], [ref only] if matches!(only.1, Statements::Empty(_)))
+        ));
+        assert!(matches!(
+            &file.statements[1].1,
+            Statements::For(node) if matches!(*node.7 .1, Statements::Empty(_))
+        ));
+        round_trip::<File>("class A { m() { ;; } }");
+    }
+}
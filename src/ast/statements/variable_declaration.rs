@@ -1,4 +1,4 @@
-use nom::{branch::alt, combinator::map, sequence::tuple, IResult};
+use nom::{branch::alt, combinator::map, sequence::tuple};
 
 use crate::{
     ast::{
@@ -6,25 +6,48 @@ use crate::{
         structure::type_struct::TypeFollowedById,
         terminal::{keyword::KwVar, symbol::Semi},
         trivia::with_trivia::WithTrivia,
+        utils::flags::Scope,
     },
-    parser::Parser,
+    parser::{IResult, Parser},
     visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
 };
 
+use super::end_of_statement;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum VariableDeclaration {
-    WithType(TypeFollowedById, AssignmentList, Option<WithTrivia<Semi>>),
-    UnspecifiedType(KwVar, AssignmentList, Option<WithTrivia<Semi>>),
+pub enum VariableDeclaration<F: Scope> {
+    WithType(
+        TypeFollowedById,
+        AssignmentList<F>,
+        Option<WithTrivia<Semi>>,
+    ),
+    UnspecifiedType(KwVar, AssignmentList<F>, Option<WithTrivia<Semi>>),
 }
 
-impl<'a> Parser<&'a str> for VariableDeclaration {
+impl<F: Scope> VariableDeclaration<F> {
+    pub fn assignments(&self) -> &AssignmentList<F> {
+        match self {
+            Self::WithType(_, assignments, _) | Self::UnspecifiedType(_, assignments, _) => {
+                assignments
+            }
+        }
+    }
+
+    pub fn semi(&self) -> Option<&WithTrivia<Semi>> {
+        match self {
+            Self::WithType(_, _, semi) | Self::UnspecifiedType(_, _, semi) => semi.as_ref(),
+        }
+    }
+}
+
+impl<'a, F: Scope> Parser<&'a str> for VariableDeclaration<F> {
     fn parse(input: &'a str) -> IResult<&'a str, Self> {
         alt((
             map(
                 tuple((
                     <_ as Parser<&'a str>>::parse,
                     <_ as Parser<&'a str>>::parse,
-                    <_ as Parser<&'a str>>::parse,
+                    end_of_statement,
                 )),
                 |(ty, assignments, semi)| Self::WithType(ty, assignments, semi),
             ),
@@ -32,7 +55,7 @@ impl<'a> Parser<&'a str> for VariableDeclaration {
                 tuple((
                     <_ as Parser<&'a str>>::parse,
                     <_ as Parser<&'a str>>::parse,
-                    <_ as Parser<&'a str>>::parse,
+                    end_of_statement,
                 )),
                 |(var, assignments, semi)| Self::UnspecifiedType(var, assignments, semi),
             ),
@@ -40,7 +63,7 @@ impl<'a> Parser<&'a str> for VariableDeclaration {
     }
 }
 
-impl<V: Visitor> Visitable<V> for VariableDeclaration {
+impl<V: Visitor, F: Scope> Visitable<V> for VariableDeclaration<F> {
     default fn accept(&self, v: &mut V) {
         match self {
             Self::WithType(ty, assignments, semi) => {
@@ -57,7 +80,7 @@ impl<V: Visitor> Visitable<V> for VariableDeclaration {
     }
 }
 
-impl<V: VisitorMut> VisitableMut<V> for VariableDeclaration {
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for VariableDeclaration<F> {
     default fn accept_mut(&mut self, v: &mut V) {
         match self {
             Self::WithType(ty, assignments, semi) => {
@@ -0,0 +1,175 @@
+use nom::{
+    combinator::{map, peek},
+    sequence::{terminated, tuple},
+};
+
+use crate::{
+    ast::{
+        expressions::parenthesized::Parenthesized,
+        terminal::{
+            keyword::{KwDo, KwWhile},
+            symbol::Semi,
+        },
+        trivia::{trivia::Trivia, with_trivia::WithTrivia},
+        utils::{flags::Scope, repeat::Repeat0},
+    },
+    parser::{IResult, Parser},
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::{end_of_statement, Statements};
+
+/// `while (condition) statement`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WhileStatement<F: Scope>(
+    pub KwWhile,
+    pub WithTrivia<Parenthesized<F>>,
+    pub WithTrivia<Box<Statements<F::Loop>>>,
+);
+impl<'a, F: Scope> Parser<&'a str> for WhileStatement<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(kw_while, condition, body)| Self(kw_while, condition, body),
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for WhileStatement<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for WhileStatement<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+/// `do statement while (condition);`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoWhileStatement<F: Scope>(
+    pub KwDo,
+    pub WithTrivia<Box<Statements<F::Loop>>>,
+    pub WithTrivia<KwWhile>,
+    pub WithTrivia<Parenthesized<F>>,
+    pub Option<WithTrivia<Semi>>,
+);
+impl<F: Scope> DoWhileStatement<F> {
+    /// Parses the body up to the `while`.
+    ///
+    /// A body such as `x++` in `do x++ while (x < 3)` has no semicolon and
+    /// does not end its line, so when the body cannot be parsed as it is, it
+    /// is parsed again up to each following `while` in turn, as if the input
+    /// ended there.
+    fn parse_body(input: &str) -> IResult<&str, WithTrivia<Box<Statements<F::Loop>>>> {
+        let error = match terminated(
+            <WithTrivia<Box<Statements<F::Loop>>> as Parser<&str>>::parse,
+            peek(<WithTrivia<KwWhile> as Parser<&str>>::parse),
+        )(input)
+        {
+            Err(nom::Err::Error(error)) => error,
+            res => return res,
+        };
+        for (end, _) in input.match_indices("while") {
+            if KwWhile::parse(&input[end..]).is_err() {
+                continue;
+            }
+            let Ok((rest, body)) = WithTrivia::<Box<Statements<F::Loop>>>::parse(&input[..end])
+            else {
+                continue;
+            };
+            if matches!(Repeat0::<Trivia>::parse(rest), Ok(("", _))) {
+                return Ok((&input[end - rest.len()..], body));
+            }
+        }
+        Err(nom::Err::Error(error))
+    }
+}
+impl<'a, F: Scope> Parser<&'a str> for DoWhileStatement<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        let (input, kw_do) = KwDo::parse(input)?;
+        let (input, body) = Self::parse_body(input)?;
+        let (input, (kw_while, condition, semi)) = tuple((
+            <_ as Parser<&'a str>>::parse,
+            <_ as Parser<&'a str>>::parse,
+            end_of_statement,
+        ))(input)?;
+        Ok((input, Self(kw_do, body, kw_while, condition, semi)))
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for DoWhileStatement<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+        v.visit(&self.3);
+        v.visit(&self.4);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for DoWhileStatement<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+        self.3.accept_mut(v);
+        self.4.accept_mut(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::{statements::Statements, structure::file::File},
+        test_utils::{rejects, round_trip},
+    };
+
+    #[test]
+    fn while_loops() {
+        let file = round_trip::<File>("while (i < 10) i++");
+        assert!(matches!(
+            &file.statements[0].1,
+            Statements::While(node) if matches!(*node.2 .1, Statements::Expression(_))
+        ));
+        round_trip::<File>("while(a){ if (b) { c() } }");
+        let file = round_trip::<File>("while (a) { break }\nwhile (b) continue");
+        assert!(matches!(
+            &file.statements[..],
+            [first, second]
+                if matches!(&first.1, Statements::While(node) if matches!(*node.2 .1, Statements::Block(_)))
+                    && matches!(&second.1, Statements::While(node) if matches!(*node.2 .1, Statements::Continue(_)))
+        ));
+        rejects::<File>("while a {}");
+        rejects::<File>("while (a) { global g }");
+        rejects::<File>("var a = 1 while (x) {}");
+        rejects::<File>("a = 1 while (x) {}");
+        rejects::<File>("if (a) b() while (x) {}");
+        rejects::<File>("do { a() while (x) {} } while (y)");
+    }
+
+    #[test]
+    fn do_while_loops() {
+        let file = round_trip::<File>("do { x-- } while (x > 0);\ndo y() \nwhile (z)");
+        let Statements::DoWhile(first) = &file.statements[0].1 else {
+            panic!("{:?}", file.statements[0]);
+        };
+        assert!(first.4.is_some());
+        let Statements::DoWhile(second) = &file.statements[1].1 else {
+            panic!("{:?}", file.statements[1]);
+        };
+        assert!(second.4.is_none());
+        round_trip::<File>("do { if (a) break; continue } while (b)");
+        round_trip::<File>("do if (a) b() else c() while (d)");
+        round_trip::<File>("do do x++ while (a) while (b)");
+        round_trip::<File>("do x = 'while' while (x)");
+        rejects::<File>("do {}");
+        rejects::<File>("do {} while (a) b()");
+    }
+}
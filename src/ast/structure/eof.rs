@@ -1,7 +1,8 @@
-use nom::IResult;
+use nom::error::{ErrorKind, ParseError as _};
 
 use crate::{
-    parser::Parser,
+    error::ParseError,
+    parser::{IResult, Parser},
     visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
 };
 
@@ -12,9 +13,9 @@ impl<'a> Parser<&'a str> for EndOfFile {
         if input.is_empty() {
             Ok((input, Self))
         } else {
-            Err(nom::Err::Error(nom::error::Error::new(
+            Err(nom::Err::Error(ParseError::from_error_kind(
                 input,
-                nom::error::ErrorKind::Eof,
+                ErrorKind::Eof,
             )))
         }
     }
@@ -1,11 +1,12 @@
-use nom::{combinator::map, sequence::pair, IResult};
+use nom::{combinator::map, sequence::pair};
 
 use crate::{
     ast::{
         statements::{GlobalFlag, Statements},
         trivia::with_trivia::WithTrivia,
+        utils::flags::{Cons, Nil},
     },
-    parser::Parser,
+    parser::{with_version, IResult, Parser, Version},
     visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
 };
 
@@ -13,9 +14,16 @@ use super::eof::EndOfFile;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct File {
-    pub statements: Vec<WithTrivia<Statements<GlobalFlag>>>,
+    pub statements: Vec<WithTrivia<Statements<Cons<GlobalFlag, Nil>>>>,
     pub eof: WithTrivia<EndOfFile>,
 }
+impl File {
+    /// Parses a file written for `version`. Parsing through [`Parser`]
+    /// assumes the latest version.
+    pub fn parse_with_version(input: &str, version: Version) -> IResult<&str, Self> {
+        with_version(version, || Self::parse(input))
+    }
+}
 impl<'a> Parser<&'a str> for File {
     fn parse(input: &'a str) -> IResult<&'a str, Self> {
         map(
@@ -0,0 +1,117 @@
+use nom::{combinator::map, sequence::tuple};
+
+use crate::{
+    ast::{
+        expressions::Expression,
+        terminal::{
+            identifier::Identifier,
+            reference::Reference,
+            symbol::{Comma, Equal, LParen, RParen},
+        },
+        trivia::with_trivia::WithTrivia,
+        utils::{flags::Scope, separated::Separated0},
+    },
+    parser::{IResult, Parser},
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::type_struct::TypeFollowedById;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParameterList<F: Scope>(
+    pub LParen,
+    pub Separated0<WithTrivia<Parameter<F>>, WithTrivia<Comma>>,
+    pub WithTrivia<RParen>,
+);
+impl<'a, F: Scope> Parser<&'a str> for ParameterList<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(lparen, parameters, rparen)| Self(lparen, parameters, rparen),
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for ParameterList<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for ParameterList<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+/// A parameter with an optional type annotation and default value, `x`,
+/// `integer x` or `x = 3`, or a v1 reference parameter `@x`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Parameter<F: Scope>(
+    pub Option<Reference>,
+    pub Option<TypeFollowedById>,
+    pub WithTrivia<Identifier>,
+    pub Option<(WithTrivia<Equal>, WithTrivia<Expression<F>>)>,
+);
+impl<F: Scope> Parameter<F> {
+    pub fn name(&self) -> &Identifier {
+        &self.2 .1
+    }
+
+    pub fn is_reference(&self) -> bool {
+        self.0.is_some()
+    }
+
+    pub fn default_value(&self) -> Option<&Expression<F>> {
+        self.3.as_ref().map(|(_, value)| &value.1)
+    }
+
+    /// Parses a parameter that cannot have a default value, such as the
+    /// single parameter of `x -> x + 1` where `x = y -> y` must remain an
+    /// assignment.
+    pub(crate) fn parse_without_default(input: &str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&str>>::parse,
+                <_ as Parser<&str>>::parse,
+                <_ as Parser<&str>>::parse,
+            )),
+            |(reference, ty, name)| Self(reference, ty, name, None),
+        )(input)
+    }
+}
+impl<'a, F: Scope> Parser<&'a str> for Parameter<F> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(reference, ty, name, default)| Self(reference, ty, name, default),
+        )(input)
+    }
+}
+impl<V: Visitor, F: Scope> Visitable<V> for Parameter<F> {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+        v.visit(&self.3);
+    }
+}
+impl<V: VisitorMut, F: Scope> VisitableMut<V> for Parameter<F> {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+        self.3.accept_mut(v);
+    }
+}
@@ -1,25 +1,53 @@
 use nom::{
-    combinator::{map, peek},
-    sequence::terminated,
-    IResult,
+    branch::alt,
+    combinator::{map, peek, verify},
+    error::{ErrorKind, ParseError as _},
+    multi::many0,
+    sequence::{pair, terminated, tuple},
 };
 
 use crate::{
-    ast::{terminal::identifier::Identifier, trivia::with_trivia::WithTrivia},
-    parser::Parser,
-    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+    ast::{
+        terminal::{
+            identifier::Identifier,
+            keyword::KwNull,
+            symbol::{Comma, EqualRAngle, LAngle, Pipe, RAngle},
+        },
+        trivia::{trivia::Trivia, with_trivia::WithTrivia},
+        utils::separated::{Separated0, Separated1},
+    },
+    error::ParseError,
+    parser::{IResult, Parser},
+    visitor::{writer::Writer, Visitable, VisitableMut, Visitor, VisitorMut},
 };
 
+/// A type that must be followed by a name on the same line, as in
+/// `integer|null x = 3`, so that a declaration is not confused with an
+/// expression.
+///
+/// The type has to be able to hold a value, see [`Type::holds_values`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TypeFollowedById(pub Type);
-impl<'a> Parser<&'a str> for TypeFollowedById {
-    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+impl TypeFollowedById {
+    /// Parses the return type written before the name of a method, which may
+    /// be `void`.
+    pub(crate) fn parse_return_type(input: &str) -> IResult<&str, Self> {
         map(
-            terminated(Type::parse, peek(WithTrivia::<Identifier>::parse)),
-            |ty| Self(ty),
+            terminated(
+                Type::parse,
+                peek(verify(WithTrivia::<Identifier>::parse, |name| {
+                    !name.0.iter().any(Trivia::has_newline)
+                })),
+            ),
+            Self,
         )(input)
     }
 }
+impl<'a> Parser<&'a str> for TypeFollowedById {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        verify(Self::parse_return_type, |ty| ty.0.holds_values())(input)
+    }
+}
 
 impl<V: Visitor> Visitable<V> for TypeFollowedById {
     default fn accept(&self, v: &mut V) {
@@ -33,21 +61,417 @@ impl<V: VisitorMut> VisitableMut<V> for TypeFollowedById {
     }
 }
 
+/// A type, or a union of types such as `integer|null`.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Type(pub Identifier);
+pub struct Type(
+    pub SimpleType,
+    pub Vec<(WithTrivia<Pipe>, WithTrivia<SimpleType>)>,
+);
+impl Type {
+    /// The members of the union, or the type itself if it is not a union.
+    pub fn alternatives(&self) -> impl Iterator<Item = &SimpleType> {
+        std::iter::once(&self.0).chain(self.1.iter().map(|(_, ty)| &ty.1))
+    }
 
+    pub fn is_union(&self) -> bool {
+        !self.1.is_empty()
+    }
+
+    /// Whether a variable can have this type: `void` cannot be part of it,
+    /// and `null` only as one member of a union.
+    pub fn holds_values(&self) -> bool {
+        !self
+            .alternatives()
+            .any(|ty| matches!(ty, SimpleType::Primitive(PrimitiveType::Void)))
+            && !self
+                .alternatives()
+                .all(|ty| matches!(ty, SimpleType::Null(_)))
+    }
+}
 impl<'a> Parser<&'a str> for Type {
     fn parse(input: &'a str) -> IResult<&'a str, Self> {
-        map(Identifier::parse, |id| Self(id))(input)
+        map(
+            pair(
+                <_ as Parser<&'a str>>::parse,
+                many0(pair(
+                    <_ as Parser<&'a str>>::parse,
+                    <_ as Parser<&'a str>>::parse,
+                )),
+            ),
+            |(first, rest)| Self(first, rest),
+        )(input)
     }
 }
 impl<V: Visitor> Visitable<V> for Type {
     default fn accept(&self, v: &mut V) {
-        v.visit(&self.0)
+        v.visit(&self.0);
+        v.visit(&self.1);
     }
 }
 impl<V: VisitorMut> VisitableMut<V> for Type {
     default fn accept_mut(&mut self, v: &mut V) {
         self.0.accept_mut(v);
+        self.1.accept_mut(v);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SimpleType {
+    Primitive(PrimitiveType),
+    Null(KwNull),
+    Function(Box<FunctionType>),
+    /// A class or a built-in container such as `Array<integer>` or
+    /// `Interval`.
+    Named(Box<NamedType>),
+}
+impl<'a> Parser<&'a str> for SimpleType {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        alt((
+            map(<_ as Parser<&'a str>>::parse, Self::Primitive),
+            map(<_ as Parser<&'a str>>::parse, Self::Null),
+            map(<_ as Parser<&'a str>>::parse, |node| {
+                Self::Function(Box::new(node))
+            }),
+            map(<_ as Parser<&'a str>>::parse, |node| {
+                Self::Named(Box::new(node))
+            }),
+        ))(input)
+    }
+}
+impl<V: Visitor> Visitable<V> for SimpleType {
+    default fn accept(&self, v: &mut V) {
+        match self {
+            Self::Primitive(node) => v.visit(node),
+            Self::Null(node) => v.visit(node),
+            Self::Function(node) => v.visit(node),
+            Self::Named(node) => v.visit(node),
+        }
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for SimpleType {
+    default fn accept_mut(&mut self, v: &mut V) {
+        match self {
+            Self::Primitive(node) => node.accept_mut(v),
+            Self::Null(node) => node.accept_mut(v),
+            Self::Function(node) => node.accept_mut(v),
+            Self::Named(node) => node.accept_mut(v),
+        }
+    }
+}
+
+/// `integer`, `real`, `string`, `boolean`, `any` or `void`.
+///
+/// Apart from `void`, these words are not reserved and remain valid names
+/// outside of types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrimitiveType {
+    Integer,
+    Real,
+    String,
+    Boolean,
+    Any,
+    Void,
+}
+impl PrimitiveType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Integer => "integer",
+            Self::Real => "real",
+            Self::String => "string",
+            Self::Boolean => "boolean",
+            Self::Any => "any",
+            Self::Void => "void",
+        }
+    }
+
+    pub fn from_word(word: &str) -> Option<Self> {
+        match word {
+            "integer" => Some(Self::Integer),
+            "real" => Some(Self::Real),
+            "string" => Some(Self::String),
+            "boolean" => Some(Self::Boolean),
+            "any" => Some(Self::Any),
+            "void" => Some(Self::Void),
+            _ => None,
+        }
+    }
+}
+impl<'a> Parser<&'a str> for PrimitiveType {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        let (rest, word) = Identifier::parse_name(input)?;
+        match Self::from_word(&word.0) {
+            Some(primitive) => Ok((rest, primitive)),
+            None => Err(nom::Err::Error(ParseError::from_error_kind(
+                input,
+                ErrorKind::Tag,
+            ))),
+        }
+    }
+}
+impl<V: Visitor> Visitable<V> for PrimitiveType {
+    default fn accept(&self, _: &mut V) {}
+}
+impl<V: VisitorMut> VisitableMut<V> for PrimitiveType {
+    default fn accept_mut(&mut self, _: &mut V) {}
+}
+impl Visitable<Writer> for PrimitiveType {
+    fn accept(&self, v: &mut Writer) {
+        v.0 += self.as_str();
+    }
+}
+
+/// `Function<integer, string => boolean>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionType(
+    pub Identifier,
+    pub WithTrivia<LAngle>,
+    pub Separated0<WithTrivia<Type>, WithTrivia<Comma>>,
+    pub WithTrivia<EqualRAngle>,
+    pub WithTrivia<Type>,
+    pub WithTrivia<RAngle>,
+);
+impl FunctionType {
+    pub fn parameters(&self) -> impl Iterator<Item = &Type> {
+        self.2 .0.iter().map(|ty| &ty.1)
+    }
+
+    pub fn return_type(&self) -> &Type {
+        &self.4 .1
+    }
+}
+impl<'a> Parser<&'a str> for FunctionType {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        let (rest, name) = Identifier::parse(input)?;
+        if name.0 != "Function" {
+            return Err(nom::Err::Error(ParseError::from_error_kind(
+                input,
+                ErrorKind::Tag,
+            )));
+        }
+        let (rest, (langle, parameters, arrow, ret, rangle)) = tuple((
+            <_ as Parser<&'a str>>::parse,
+            <_ as Parser<&'a str>>::parse,
+            <_ as Parser<&'a str>>::parse,
+            <_ as Parser<&'a str>>::parse,
+            <_ as Parser<&'a str>>::parse,
+        ))(rest)?;
+        Ok((rest, Self(name, langle, parameters, arrow, ret, rangle)))
+    }
+}
+impl<V: Visitor> Visitable<V> for FunctionType {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+        v.visit(&self.3);
+        v.visit(&self.4);
+        v.visit(&self.5);
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for FunctionType {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+        self.3.accept_mut(v);
+        self.4.accept_mut(v);
+        self.5.accept_mut(v);
+    }
+}
+
+/// A type named by an identifier, with its type arguments if it has any, as
+/// in `Map<string, real>`.
+///
+/// Only the built-in [`NamedType::GENERIC`] containers take arguments, so
+/// that `a < b > c` remains a comparison.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedType(pub Identifier, pub Option<WithTrivia<TypeArguments>>);
+impl NamedType {
+    /// The types written with type arguments.
+    pub const GENERIC: [&'static str; 3] = ["Array", "Map", "Set"];
+
+    pub fn name(&self) -> &Identifier {
+        &self.0
+    }
+
+    pub fn arguments(&self) -> impl Iterator<Item = &Type> {
+        self.1
+            .iter()
+            .flat_map(|args| args.1 .1 .0.iter().map(|ty| &ty.1))
+    }
+}
+impl<'a> Parser<&'a str> for NamedType {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        let (rest, name) = Identifier::parse(input)?;
+        if !Self::GENERIC.contains(&name.0.as_str()) {
+            return Ok((rest, Self(name, None)));
+        }
+        let (rest, arguments) = <_ as Parser<&'a str>>::parse(rest)?;
+        Ok((rest, Self(name, arguments)))
+    }
+}
+impl<V: Visitor> Visitable<V> for NamedType {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for NamedType {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+    }
+}
+
+/// `<string, real>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeArguments(
+    pub LAngle,
+    pub Separated1<WithTrivia<Type>, WithTrivia<Comma>>,
+    pub WithTrivia<RAngle>,
+);
+impl<'a> Parser<&'a str> for TypeArguments {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+                <_ as Parser<&'a str>>::parse,
+            )),
+            |(langle, arguments, rangle)| Self(langle, arguments, rangle),
+        )(input)
+    }
+}
+impl<V: Visitor> Visitable<V> for TypeArguments {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+        v.visit(&self.1);
+        v.visit(&self.2);
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for TypeArguments {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+        self.1.accept_mut(v);
+        self.2.accept_mut(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PrimitiveType, SimpleType, Type};
+    use crate::{
+        ast::{
+            statements::{variable_declaration::VariableDeclaration, Statements},
+            structure::file::File,
+        },
+        parser::Parser,
+        test_utils::{rejects, round_trip, write, Expression},
+    };
+
+    fn declared_type(src: &str) -> Type {
+        let file = round_trip::<File>(src);
+        match &file.statements[0].1 {
+            Statements::VariableDeclaration(VariableDeclaration::WithType(ty, ..)) => ty.0.clone(),
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn unions() {
+        let ty = declared_type("integer|null x = 3");
+        assert!(ty.is_union());
+        let alternatives: Vec<_> = ty.alternatives().collect();
+        assert!(matches!(
+            alternatives[..],
+            [
+                SimpleType::Primitive(PrimitiveType::Integer),
+                SimpleType::Null(_)
+            ]
+        ));
+        round_trip::<File>("integer | null x = 3;\nFoo|Bar|null o");
+        assert!(!round_trip::<Type>("string").is_union());
+    }
+
+    #[test]
+    fn generics() {
+        let ty = declared_type("Map<string, real> m = [:]");
+        let SimpleType::Named(named) = &ty.0 else {
+            panic!("{ty:?}");
+        };
+        assert_eq!(named.name().0, "Map");
+        let arguments: Vec<_> = named.arguments().map(write).collect();
+        assert_eq!(arguments, ["string", "real"]);
+        round_trip::<File>("Array<integer> a = []\nSet<T> s\nInterval i");
+        round_trip::<File>("Array<Array<integer>> nested");
+        round_trip::<File>("Map<string, Array<real>> m");
+    }
+
+    #[test]
+    fn function_types() {
+        let ty = declared_type("Function<integer, string => boolean> f");
+        let SimpleType::Function(function) = &ty.0 else {
+            panic!("{ty:?}");
+        };
+        let parameters: Vec<_> = function.parameters().map(write).collect();
+        assert_eq!(parameters, ["integer", "string"]);
+        assert_eq!(write(function.return_type()), "boolean");
+        round_trip::<File>("Function< => void> g");
+        round_trip::<File>("function f(integer|null a) -> Function<any => void> { return null }");
+    }
+
+    #[test]
+    fn comparisons_are_not_declarations() {
+        for src in ["a < b > c", "a < b > c;", "x < y"] {
+            let file = round_trip::<File>(src);
+            assert!(
+                matches!(file.statements[0].1, Statements::Expression(_)),
+                "{src}"
+            );
+        }
+        assert!(matches!(
+            round_trip::<Expression>("a < b > c"),
+            Expression::Binary(_)
+        ));
+        round_trip::<File>("var v = a < b\nvar w = a | b");
+        round_trip::<File>("var string = 1\nstring = 2");
+    }
+
+    #[test]
+    fn names_on_a_later_line_are_not_declared() {
+        for src in [
+            "a\nb = 1",
+            "x | y\nb = 1",
+            "integer\nx = 3",
+            "a // t\nb = 1",
+        ] {
+            let file = round_trip::<File>(src);
+            assert_eq!(file.statements.len(), 2, "{src}");
+            assert!(
+                file.statements
+                    .iter()
+                    .all(|statement| matches!(statement.1, Statements::Expression(_))),
+                "{src}"
+            );
+        }
+        declared_type("integer /* t */ x = 3");
+    }
+
+    #[test]
+    fn variables_hold_values() {
+        rejects::<File>("void x = 1;");
+        rejects::<File>("null x;");
+        rejects::<File>("void|integer x");
+        rejects::<File>("function f(void a) {}");
+        rejects::<File>("global null g");
+        round_trip::<File>("null|integer x");
+        round_trip::<File>("class A { void m() {} null|A parent }");
+        rejects::<File>("class A { void x = 1 }");
+    }
+
+    #[test]
+    fn types_stop_at_operators() {
+        let (rest, _) = Type::parse("integer || b").unwrap();
+        assert_eq!(rest, " || b");
     }
 }
@@ -4,11 +4,11 @@ use nom::{
     character::complete::{alpha1, alphanumeric1},
     combinator::{map, not},
     multi::many0,
-    sequence::tuple,
+    sequence::{pair, preceded},
 };
 
 use crate::{
-    parser::Parser,
+    parser::{IResult, Parser},
     visitor::{writer::Writer, Visitable, VisitableMut, Visitor, VisitorMut},
 };
 
@@ -17,16 +17,16 @@ use super::keyword::Keywords;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Identifier(pub String);
 
-impl<'a> Parser<&'a str> for Identifier {
-    fn parse(input: &'a str) -> nom::IResult<&'a str, Self> {
-        // Recognise !Keywords ~ [a-zA-Z_][a-zA-Z0-9_]*
+impl Identifier {
+    /// Recognise [a-zA-Z_][a-zA-Z0-9_]*, reserved words included, for names
+    /// that cannot be confused with a keyword such as `a.class`.
+    pub fn parse_name(input: &str) -> IResult<&str, Self> {
         map(
-            tuple((
-                not(Keywords::parse),
+            pair(
                 alt((alpha1, tag("_"))),
                 many0(alt((alphanumeric1, tag("_")))),
-            )),
-            |(_, head, tail)| {
+            ),
+            |(head, tail)| {
                 let mut ident = String::from(head);
                 ident.push_str(&tail.join(""));
                 Self(ident)
@@ -35,6 +35,13 @@ impl<'a> Parser<&'a str> for Identifier {
     }
 }
 
+impl<'a> Parser<&'a str> for Identifier {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        // Recognise !Keywords ~ [a-zA-Z_][a-zA-Z0-9_]*
+        preceded(not(Keywords::parse), Self::parse_name)(input)
+    }
+}
+
 impl<V: Visitor> Visitable<V> for Identifier {
     default fn accept(&self, _: &mut V) {}
 }
@@ -1,95 +1,278 @@
 use nom::{
     branch::alt,
-    bytes::complete::tag,
+    bytes::complete::{tag, take_while, take_while1},
     character::complete::alphanumeric1,
-    combinator::{map, not, peek},
-    sequence::terminated,
+    combinator::{map, not, peek, recognize},
+    error::{ErrorKind, ParseError as _},
+    sequence::{pair, terminated},
 };
 
 use crate::{
-    parser::Parser,
+    error::ParseError,
+    parser::{version, IResult, Parser, Version},
     visitor::{writer::Writer, Visitable, VisitableMut, Visitor, VisitorMut},
 };
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum Keywords {
-    Global(KwGlobal),
-    Var(KwVar),
-}
+macro_rules! define_keyword {
+    // Match a list of keywords: Variant(Name) => "word" since Version
+    ( $( $variant:ident($name:ident) => $word:literal since $since:ident ),* $(,)? )
+    => {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum Keywords {
+            $( $variant($name), )*
+        }
 
-impl<'a> Parser<&'a str> for Keywords {
-    fn parse(input: &'a str) -> nom::IResult<&'a str, Self> {
-        alt((
-            map(KwGlobal::parse, Keywords::Global),
-            map(KwVar::parse, Keywords::Var),
-        ))(input)
-    }
-}
+        impl Keywords {
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $( Self::$variant(_) => $word, )*
+                }
+            }
+
+            /// First language version in which the word is reserved.
+            pub fn since(&self) -> Version {
+                match self {
+                    $( Self::$variant(_) => Version::$since, )*
+                }
+            }
 
-impl<V: Visitor> Visitable<V> for Keywords {
-    default fn accept(&self, v: &mut V) {
-        match self {
-            Keywords::Global(node) => v.visit(node),
-            Keywords::Var(node) => v.visit(node),
+            pub fn from_word(word: &str) -> Option<Self> {
+                match word {
+                    $( $word => Some(Self::$variant($name)), )*
+                    _ => None,
+                }
+            }
         }
-    }
-}
 
-impl<V: VisitorMut> VisitableMut<V> for Keywords {
-    default fn accept_mut(&mut self, v: &mut V) {
-        match self {
-            Keywords::Global(node) => node.accept_mut(v),
-            Keywords::Var(node) => node.accept_mut(v),
+        impl<V: Visitor> Visitable<V> for Keywords {
+            default fn accept(&self, v: &mut V) {
+                match self {
+                    $( Self::$variant(node) => v.visit(node), )*
+                }
+            }
         }
-    }
+
+        impl<V: VisitorMut> VisitableMut<V> for Keywords {
+            default fn accept_mut(&mut self, v: &mut V) {
+                match self {
+                    $( Self::$variant(node) => node.accept_mut(v), )*
+                }
+            }
+        }
+
+        $(
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            pub struct $name;
+
+            impl<'a> Parser<&'a str> for $name {
+                /// Recognises the word if it is reserved in the current
+                /// language version.
+                fn parse(input: &'a str) -> IResult<&'a str, Self> {
+                    if Version::$since > version() {
+                        return Err(nom::Err::Error(ParseError::from_error_kind(
+                            input,
+                            ErrorKind::Tag,
+                        )));
+                    }
+                    map(
+                        terminated(tag($word), peek(not(alt((alphanumeric1, tag("_")))))),
+                        |_| $name,
+                    )(input)
+                }
+            }
+
+            impl<V: Visitor> Visitable<V> for $name {
+                default fn accept(&self, _: &mut V) {}
+            }
+
+            impl<V: VisitorMut> VisitableMut<V> for $name {
+                default fn accept_mut(&mut self, _: &mut V) {}
+            }
+
+            impl Visitable<Writer> for $name {
+                fn accept(&self, v: &mut Writer) {
+                    v.0 += $word;
+                }
+            }
+        )*
+    };
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct KwGlobal;
-impl<'a> Parser<&'a str> for KwGlobal {
-    fn parse(input: &'a str) -> nom::IResult<&'a str, Self> {
-        map(
-            terminated(tag("global"), peek(not(alt((alphanumeric1, tag("_")))))),
-            |_| KwGlobal,
-        )(input)
+impl<'a> Parser<&'a str> for Keywords {
+    /// Recognises a word reserved in the current language version.
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        let (rest, word) = recognize(pair(
+            take_while1(|c: char| c.is_ascii_alphabetic() || c == '_'),
+            take_while(|c: char| c.is_ascii_alphanumeric() || c == '_'),
+        ))(input)?;
+        match Self::from_word(word) {
+            Some(keyword) if keyword.since() <= version() => Ok((rest, keyword)),
+            _ => Err(nom::Err::Error(ParseError::from_error_kind(
+                input,
+                ErrorKind::Tag,
+            ))),
+        }
     }
 }
 
-impl<V: Visitor> Visitable<V> for KwGlobal {
-    default fn accept(&self, _: &mut V) {}
+define_keyword! {
+    // Core language
+    And(KwAnd) => "and" since V1,
+    Break(KwBreak) => "break" since V1,
+    Continue(KwContinue) => "continue" since V1,
+    Do(KwDo) => "do" since V1,
+    Else(KwElse) => "else" since V1,
+    False(KwFalse) => "false" since V1,
+    For(KwFor) => "for" since V1,
+    Function(KwFunction) => "function" since V1,
+    Global(KwGlobal) => "global" since V1,
+    If(KwIf) => "if" since V1,
+    In(KwIn) => "in" since V1,
+    Include(KwInclude) => "include" since V1,
+    Not(KwNot) => "not" since V1,
+    Null(KwNull) => "null" since V1,
+    Or(KwOr) => "or" since V1,
+    Return(KwReturn) => "return" since V1,
+    True(KwTrue) => "true" since V1,
+    Var(KwVar) => "var" since V1,
+    While(KwWhile) => "while" since V1,
+    Xor(KwXor) => "xor" since V1,
+    // Classes
+    Class(KwClass) => "class" since V2,
+    Constructor(KwConstructor) => "constructor" since V2,
+    Extends(KwExtends) => "extends" since V2,
+    Final(KwFinal) => "final" since V2,
+    Instanceof(KwInstanceof) => "instanceof" since V2,
+    New(KwNew) => "new" since V2,
+    Private(KwPrivate) => "private" since V2,
+    Protected(KwProtected) => "protected" since V2,
+    Public(KwPublic) => "public" since V2,
+    Static(KwStatic) => "static" since V2,
+    Super(KwSuper) => "super" since V2,
+    This(KwThis) => "this" since V2,
+    // Reserved for future use
+    Abstract(KwAbstract) => "abstract" since V3,
+    Arguments(KwArguments) => "arguments" since V3,
+    Await(KwAwait) => "await" since V3,
+    Byte(KwByte) => "byte" since V3,
+    Case(KwCase) => "case" since V3,
+    Catch(KwCatch) => "catch" since V3,
+    Char(KwChar) => "char" since V3,
+    Const(KwConst) => "const" since V3,
+    Default(KwDefault) => "default" since V3,
+    Double(KwDouble) => "double" since V3,
+    Enum(KwEnum) => "enum" since V3,
+    Eval(KwEval) => "eval" since V3,
+    Export(KwExport) => "export" since V3,
+    Finally(KwFinally) => "finally" since V3,
+    Float(KwFloat) => "float" since V3,
+    Goto(KwGoto) => "goto" since V3,
+    Implements(KwImplements) => "implements" since V3,
+    Import(KwImport) => "import" since V3,
+    Int(KwInt) => "int" since V3,
+    Interface(KwInterface) => "interface" since V3,
+    Let(KwLet) => "let" since V3,
+    Long(KwLong) => "long" since V3,
+    Native(KwNative) => "native" since V3,
+    Package(KwPackage) => "package" since V3,
+    Short(KwShort) => "short" since V3,
+    Switch(KwSwitch) => "switch" since V3,
+    Synchronized(KwSynchronized) => "synchronized" since V3,
+    Throw(KwThrow) => "throw" since V3,
+    Throws(KwThrows) => "throws" since V3,
+    Transient(KwTransient) => "transient" since V3,
+    Try(KwTry) => "try" since V3,
+    Typeof(KwTypeof) => "typeof" since V3,
+    Void(KwVoid) => "void" since V3,
+    Volatile(KwVolatile) => "volatile" since V3,
+    With(KwWith) => "with" since V3,
+    Yield(KwYield) => "yield" since V3,
+    // Typing
+    As(KwAs) => "as" since V4,
 }
 
-impl<V: VisitorMut> VisitableMut<V> for KwGlobal {
-    default fn accept_mut(&mut self, _: &mut V) {}
-}
+#[cfg(test)]
+mod tests {
+    use super::{Keywords, KwAs, KwClass, KwSwitch, KwThis, KwWhile};
+    use crate::{
+        ast::structure::file::File,
+        parser::{with_version, Parser, Version},
+        test_utils::{rejects, round_trip, Expression},
+    };
 
-impl Visitable<Writer> for KwGlobal {
-    fn accept(&self, v: &mut Writer) {
-        v.0 += "global";
+    fn reserved(word: &str) -> bool {
+        matches!(Keywords::parse(word), Ok(("", _)))
     }
-}
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct KwVar;
-impl<'a> Parser<&'a str> for KwVar {
-    fn parse(input: &'a str) -> nom::IResult<&'a str, Self> {
-        map(
-            terminated(tag("var"), peek(not(alt((alphanumeric1, tag("_")))))),
-            |_| KwVar,
-        )(input)
+    #[test]
+    fn reserved_words_by_version() {
+        with_version(Version::V1, || {
+            assert!(reserved("while") && reserved("include"));
+            assert!(!reserved("class") && !reserved("switch") && !reserved("as"));
+        });
+        with_version(Version::V2, || {
+            assert!(reserved("class") && reserved("this") && reserved("static"));
+            assert!(!reserved("switch") && !reserved("as"));
+        });
+        with_version(Version::V3, || {
+            assert!(reserved("switch") && reserved("void"));
+            assert!(!reserved("as"));
+        });
+        with_version(Version::V4, || assert!(reserved("as")));
+        assert!(!reserved("classy") && !reserved("whilex"));
     }
-}
 
-impl<V: Visitor> Visitable<V> for KwVar {
-    default fn accept(&self, _: &mut V) {}
-}
+    #[test]
+    fn keyword_tokens_by_version() {
+        with_version(Version::V1, || {
+            assert!(KwWhile::parse("while").is_ok());
+            assert!(KwClass::parse("class").is_err());
+            assert!(KwThis::parse("this").is_err());
+        });
+        with_version(Version::V2, || {
+            assert!(KwClass::parse("class").is_ok());
+            assert!(KwSwitch::parse("switch").is_err());
+        });
+        with_version(Version::V3, || {
+            assert!(KwSwitch::parse("switch").is_ok());
+            assert!(KwAs::parse("as").is_err());
+        });
+        assert!(KwAs::parse("as").is_ok());
+        assert!(KwClass::parse("classy").is_err());
+    }
 
-impl<V: VisitorMut> VisitableMut<V> for KwVar {
-    default fn accept_mut(&mut self, _: &mut V) {}
-}
+    #[test]
+    fn words_are_either_names_or_keywords() {
+        with_version(Version::V1, || {
+            round_trip::<File>("var class = 1, as = 2, static = 3");
+            rejects::<File>("class A {}");
+            rejects::<Expression>("a instanceof B");
+        });
+        with_version(Version::V2, || {
+            round_trip::<File>("class A {}");
+            round_trip::<File>("var switch = 1");
+            rejects::<File>("var class = 1");
+        });
+        with_version(Version::V3, || {
+            rejects::<File>("var switch = 1");
+            round_trip::<File>("var as = 1");
+        });
+        rejects::<File>("var as = 1");
+        rejects::<File>("var function = 1");
+        round_trip::<File>("var variable = 1, functions = 2, classy = 3");
+    }
 
-impl Visitable<Writer> for KwVar {
-    fn accept(&self, v: &mut Writer) {
-        v.0 += "var";
+    #[test]
+    fn files_are_parsed_for_a_given_version() {
+        let src = "var switch = 1";
+        assert!(matches!(
+            File::parse_with_version(src, Version::V2),
+            Ok(("", _))
+        ));
+        assert!(!matches!(
+            File::parse_with_version(src, Version::V3),
+            Ok(("", _))
+        ));
+        assert!(!matches!(File::parse(src), Ok(("", _))));
     }
 }
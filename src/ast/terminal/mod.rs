@@ -1,3 +1,6 @@
 pub mod identifier;
 pub mod keyword;
+pub mod number;
+pub mod reference;
+pub mod string;
 pub mod symbol;
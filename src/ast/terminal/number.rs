@@ -0,0 +1,233 @@
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, tag_no_case, take_while1},
+    character::complete::{alphanumeric1, one_of},
+    combinator::{map, map_res, not, opt, peek, recognize},
+    multi::many0,
+    sequence::{pair, terminated, tuple},
+};
+
+use crate::{
+    error::{ParseError, ParseErrorKind},
+    parser::{IResult, Parser},
+    visitor::{writer::Writer, Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+/// Recognise digits of `radix` in groups separated by single underscores, so
+/// that a literal neither starts nor ends with `_`.
+fn digits_in<'a>(radix: u32) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str> {
+    let group = move |input| take_while1(move |c: char| c.is_digit(radix))(input);
+    recognize(pair(group, many0(pair(tag("_"), group))))
+}
+
+/// Recognise [0-9]+(_[0-9]+)*
+fn digits(input: &str) -> IResult<&str, &str> {
+    digits_in(10)(input)
+}
+
+/// A number must not run into an identifier, e.g. `12abc`.
+fn boundary(input: &str) -> IResult<&str, ()> {
+    peek(not(alt((alphanumeric1, tag("_")))))(input)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegerLiteral {
+    /// The literal as written in the source, e.g. `0xFF` or `1_000`.
+    pub raw: String,
+    pub value: i64,
+}
+
+impl<'a> Parser<&'a str> for IntegerLiteral {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        let prefixed = |prefix: &'static str, radix: u32| {
+            move |input: &'a str| {
+                let (rest, _) = tag_no_case(prefix)(input)?;
+                match digits_in(radix)(rest) {
+                    Ok((rest, digits)) => Ok((rest, (digits, radix))),
+                    Err(nom::Err::Error(_)) => Err(nom::Err::Failure(ParseError::new(
+                        input,
+                        ParseErrorKind::MissingDigits,
+                    ))),
+                    Err(e) => Err(e),
+                }
+            }
+        };
+        let (rest, (digits, radix)) = terminated(
+            alt((
+                prefixed("0x", 16),
+                prefixed("0b", 2),
+                map(digits, |digits| (digits, 10)),
+            )),
+            boundary,
+        )(input)?;
+        let digits = digits.replace('_', "");
+        let value = if radix == 10 {
+            digits.parse::<i64>().ok()
+        } else {
+            // Hexadecimal and binary literals spell out the bit pattern, so
+            // the full 64 bits are accepted.
+            u64::from_str_radix(&digits, radix)
+                .ok()
+                .map(|value| value as i64)
+        };
+        let Some(value) = value else {
+            return Err(nom::Err::Failure(ParseError::new(
+                input,
+                ParseErrorKind::IntegerOverflow,
+            )));
+        };
+        Ok((
+            rest,
+            Self {
+                raw: input[..input.len() - rest.len()].into(),
+                value,
+            },
+        ))
+    }
+}
+
+impl<V: Visitor> Visitable<V> for IntegerLiteral {
+    default fn accept(&self, _: &mut V) {}
+}
+
+impl<V: VisitorMut> VisitableMut<V> for IntegerLiteral {
+    default fn accept_mut(&mut self, _: &mut V) {}
+}
+
+impl Visitable<Writer> for IntegerLiteral {
+    fn accept(&self, v: &mut Writer) {
+        v.0 += self.raw.as_str();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RealLiteral {
+    /// The literal as written in the source, e.g. `1.5e3` or `π`.
+    pub raw: String,
+    pub value: f64,
+}
+
+// A literal never decodes to NaN, so equality is total.
+impl Eq for RealLiteral {}
+
+impl<'a> Parser<&'a str> for RealLiteral {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        let exponent = || recognize(tuple((one_of("eE"), opt(one_of("+-")), digits)));
+        let decimal = map_res(
+            terminated(
+                recognize(alt((
+                    recognize(tuple((digits, tag("."), digits, opt(exponent())))),
+                    recognize(pair(digits, exponent())),
+                ))),
+                boundary,
+            ),
+            |raw: &'a str| raw.replace('_', "").parse().map(|value| (raw, value)),
+        );
+        map(
+            alt((
+                map(terminated(tag("∞"), boundary), |raw| (raw, f64::INFINITY)),
+                map(terminated(tag("π"), boundary), |raw| {
+                    (raw, std::f64::consts::PI)
+                }),
+                decimal,
+            )),
+            |(raw, value): (&'a str, f64)| Self {
+                raw: raw.into(),
+                value,
+            },
+        )(input)
+    }
+}
+
+impl<V: Visitor> Visitable<V> for RealLiteral {
+    default fn accept(&self, _: &mut V) {}
+}
+
+impl<V: VisitorMut> VisitableMut<V> for RealLiteral {
+    default fn accept_mut(&mut self, _: &mut V) {}
+}
+
+impl Visitable<Writer> for RealLiteral {
+    fn accept(&self, v: &mut Writer) {
+        v.0 += self.raw.as_str();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IntegerLiteral, RealLiteral};
+    use crate::{
+        error::ParseErrorKind,
+        test_utils::{error_kind, rejects, round_trip, Expression},
+    };
+
+    #[test]
+    fn integers() {
+        assert_eq!(round_trip::<IntegerLiteral>("1_000").value, 1000);
+        assert_eq!(round_trip::<IntegerLiteral>("0xFF").value, 255);
+        assert_eq!(round_trip::<IntegerLiteral>("0XfF").value, 255);
+        assert_eq!(round_trip::<IntegerLiteral>("0b1010_1010").value, 170);
+        assert_eq!(
+            round_trip::<IntegerLiteral>("0xFFFF_FFFF_FFFF_FFFF").value,
+            -1
+        );
+        assert_eq!(
+            round_trip::<IntegerLiteral>("9223372036854775807").value,
+            i64::MAX
+        );
+    }
+
+    #[test]
+    fn reals() {
+        assert_eq!(round_trip::<RealLiteral>("12.5").value, 12.5);
+        assert_eq!(round_trip::<RealLiteral>("1.5e3").value, 1500.0);
+        assert_eq!(round_trip::<RealLiteral>("3E-2").value, 0.03);
+        assert_eq!(round_trip::<RealLiteral>("1_0.2_5").value, 10.25);
+        assert_eq!(round_trip::<RealLiteral>("∞").value, f64::INFINITY);
+        assert_eq!(round_trip::<RealLiteral>("π").value, std::f64::consts::PI);
+    }
+
+    #[test]
+    fn underscores_separate_digits() {
+        rejects::<IntegerLiteral>("1_");
+        rejects::<IntegerLiteral>("1__0");
+        rejects::<IntegerLiteral>("0x_FF");
+        rejects::<IntegerLiteral>("0xFF_");
+        rejects::<RealLiteral>("1_.5");
+        rejects::<RealLiteral>("1.5_");
+        rejects::<Expression>("1_");
+    }
+
+    #[test]
+    fn literals_end_at_a_word_boundary() {
+        rejects::<IntegerLiteral>("12abc");
+        rejects::<RealLiteral>("1.5x");
+        rejects::<RealLiteral>("πx");
+        rejects::<RealLiteral>("∞2");
+        rejects::<Expression>("π_");
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            error_kind::<IntegerLiteral>("99999999999999999999"),
+            ParseErrorKind::IntegerOverflow
+        );
+        assert_eq!(
+            error_kind::<IntegerLiteral>("0x1_0000_0000_0000_0000"),
+            ParseErrorKind::IntegerOverflow
+        );
+        assert_eq!(
+            error_kind::<IntegerLiteral>("0x"),
+            ParseErrorKind::MissingDigits
+        );
+        assert_eq!(
+            error_kind::<IntegerLiteral>("0b2"),
+            ParseErrorKind::MissingDigits
+        );
+        assert_eq!(
+            error_kind::<Expression>("99999999999999999999"),
+            ParseErrorKind::IntegerOverflow
+        );
+    }
+}
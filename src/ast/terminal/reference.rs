@@ -0,0 +1,92 @@
+use crate::{
+    error::{ParseError, ParseErrorKind},
+    parser::{version, IResult, Parser, Version},
+    visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+use super::symbol::At;
+
+/// The `@` marker of LeekScript v1, binding a parameter, a variable or a
+/// value by reference as in `function f(@arr)` or `var @x = y`.
+///
+/// Later versions pass every value the same way, so the marker is a hard
+/// error when parsing for any version after v1, see
+/// [`File::parse_with_version`](crate::ast::structure::file::File::parse_with_version).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reference(pub At);
+impl<'a> Parser<&'a str> for Reference {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        let (rest, at) = At::parse(input)?;
+        if version() > Version::V1 {
+            return Err(nom::Err::Failure(ParseError::new(
+                input,
+                ParseErrorKind::ReferenceNotSupported,
+            )));
+        }
+        Ok((rest, Self(at)))
+    }
+}
+impl<V: Visitor> Visitable<V> for Reference {
+    default fn accept(&self, v: &mut V) {
+        v.visit(&self.0);
+    }
+}
+impl<V: VisitorMut> VisitableMut<V> for Reference {
+    default fn accept_mut(&mut self, v: &mut V) {
+        self.0.accept_mut(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::{
+            structure::{file::File, parameter::Parameter},
+            utils::flags::Nil,
+        },
+        error::ParseErrorKind,
+        parser::{with_version, Version},
+        test_utils::{error_kind, grouped, round_trip, Expression},
+    };
+
+    #[test]
+    fn v1_references() {
+        with_version(Version::V1, || {
+            round_trip::<File>("var @x = @y, z = 1");
+            round_trip::<File>("function f(@arr, b) { var @c }");
+            assert!(round_trip::<Parameter<Nil>>("@arr").is_reference());
+            assert!(!round_trip::<Parameter<Nil>>("arr").is_reference());
+            assert_eq!(grouped("f(@y)"), "f((@ y))");
+            assert!(matches!(round_trip("@a -> a"), Expression::Lambda(_)));
+        });
+    }
+
+    #[test]
+    fn references_are_v1_only() {
+        for src in ["var @x = y", "var x = f(@y)", "var g = function(@arr) {}"] {
+            assert_eq!(
+                error_kind::<File>(src),
+                ParseErrorKind::ReferenceNotSupported,
+                "{src}"
+            );
+            with_version(Version::V2, || {
+                assert_eq!(
+                    error_kind::<File>(src),
+                    ParseErrorKind::ReferenceNotSupported
+                )
+            });
+        }
+    }
+
+    #[test]
+    fn version_is_given_with_the_source() {
+        let src = "function f(@arr) {}";
+        let (rest, _) = File::parse_with_version(src, Version::V1).unwrap();
+        assert_eq!(rest, "");
+        let Err(nom::Err::Failure(error)) = File::parse_with_version(src, Version::V4) else {
+            panic!("{src:?} parses in v4");
+        };
+        assert_eq!(error.kind, ParseErrorKind::ReferenceNotSupported);
+        assert_eq!(error.input, "@arr) {}");
+    }
+}
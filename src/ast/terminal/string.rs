@@ -0,0 +1,183 @@
+use nom::character::complete::one_of;
+
+use crate::{
+    error::{ParseError, ParseErrorKind},
+    parser::{IResult, Parser},
+    visitor::{writer::Writer, Visitable, VisitableMut, Visitor, VisitorMut},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StringLiteral {
+    /// The literal as written in the source, quotes included.
+    pub raw: String,
+    /// The content of the literal with escape sequences decoded.
+    pub value: String,
+}
+
+impl StringLiteral {
+    pub fn quote(&self) -> char {
+        self.raw.chars().next().unwrap_or('"')
+    }
+}
+
+impl<'a> Parser<&'a str> for StringLiteral {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        let (body, quote) = one_of("'\"")(input)?;
+        let unterminated =
+            || nom::Err::Failure(ParseError::new(input, ParseErrorKind::UnterminatedString));
+
+        let mut value = String::new();
+        let mut chars = body.char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                c if c == quote => {
+                    let end = quote.len_utf8() + i + c.len_utf8();
+                    let raw = &input[..end];
+                    return Ok((
+                        &input[end..],
+                        Self {
+                            raw: raw.into(),
+                            value,
+                        },
+                    ));
+                }
+                '\\' => {
+                    let (_, escaped) = chars.next().ok_or_else(unterminated)?;
+                    value.push(match escaped {
+                        'n' => '\n',
+                        't' => '\t',
+                        'r' => '\r',
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        '0' => '\0',
+                        'u' => {
+                            let at = &body[i..];
+                            let invalid = || {
+                                nom::Err::Failure(ParseError::new(
+                                    at,
+                                    ParseErrorKind::InvalidEscape,
+                                ))
+                            };
+                            let hex = body
+                                .get(i + 2..i + 6)
+                                .filter(|hex| hex.chars().all(|c| c.is_ascii_hexdigit()))
+                                .ok_or_else(invalid)?;
+                            let decoded = u32::from_str_radix(hex, 16)
+                                .ok()
+                                .and_then(char::from_u32)
+                                .ok_or_else(invalid)?;
+                            chars.nth(3);
+                            decoded
+                        }
+                        // `\\`, `\'`, `\"` and any other escaped character
+                        // stand for themselves.
+                        other => other,
+                    });
+                }
+                c => value.push(c),
+            }
+        }
+        Err(unterminated())
+    }
+}
+
+impl<V: Visitor> Visitable<V> for StringLiteral {
+    default fn accept(&self, _: &mut V) {}
+}
+
+impl<V: VisitorMut> VisitableMut<V> for StringLiteral {
+    default fn accept_mut(&mut self, _: &mut V) {}
+}
+
+impl Visitable<Writer> for StringLiteral {
+    fn accept(&self, v: &mut Writer) {
+        v.0 += self.raw.as_str();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StringLiteral;
+    use crate::{
+        ast::structure::file::File,
+        error::ParseErrorKind,
+        parser::Parser,
+        test_utils::{error_kind, round_trip},
+    };
+
+    fn value(src: &str) -> String {
+        round_trip::<StringLiteral>(src).value
+    }
+
+    #[test]
+    fn quotes() {
+        assert_eq!(value(r#""abc""#), "abc");
+        assert_eq!(value("'abc'"), "abc");
+        assert_eq!(value(r#""it's""#), "it's");
+        assert_eq!(value(r#"'say "hi"'"#), r#"say "hi""#);
+        assert_eq!(value("''"), "");
+        assert_eq!(round_trip::<StringLiteral>("'a'").quote(), '\'');
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(value(r#""a\"b""#), "a\"b");
+        assert_eq!(value(r"'it\'s'"), "it's");
+        assert_eq!(value(r#""\n\t\r\b\f\0""#), "\n\t\r\u{8}\u{c}\0");
+        assert_eq!(value(r#""\\""#), "\\");
+        assert_eq!(value(r#""\u00e9\u2190""#), "é←");
+        assert_eq!(value(r#""\q""#), "q");
+    }
+
+    #[test]
+    fn multibyte_content() {
+        assert_eq!(value("\"éé\""), "éé");
+        assert_eq!(value("'π → ∞'"), "π → ∞");
+        let (rest, _) = StringLiteral::parse("'é' + 1").unwrap();
+        assert_eq!(rest, " + 1");
+    }
+
+    #[test]
+    fn unterminated() {
+        assert_eq!(
+            error_kind::<StringLiteral>("'abc"),
+            ParseErrorKind::UnterminatedString
+        );
+        assert_eq!(
+            error_kind::<StringLiteral>(r#""abc\""#),
+            ParseErrorKind::UnterminatedString
+        );
+        assert_eq!(
+            error_kind::<StringLiteral>(r#""abc\"#),
+            ParseErrorKind::UnterminatedString
+        );
+        assert_eq!(
+            error_kind::<File>("var a = \"abc"),
+            ParseErrorKind::UnterminatedString
+        );
+        match StringLiteral::parse("'abc") {
+            Err(nom::Err::Failure(e)) => assert_eq!(e.input, "'abc"),
+            res => panic!("{res:?}"),
+        }
+    }
+
+    #[test]
+    fn invalid_escapes() {
+        assert_eq!(
+            error_kind::<StringLiteral>(r#""\u12""#),
+            ParseErrorKind::InvalidEscape
+        );
+        assert_eq!(
+            error_kind::<StringLiteral>(r#""\uzzzz""#),
+            ParseErrorKind::InvalidEscape
+        );
+        assert_eq!(
+            error_kind::<StringLiteral>(r#""\ud800""#),
+            ParseErrorKind::InvalidEscape
+        );
+        match StringLiteral::parse(r#""ab\uzzzz""#) {
+            Err(nom::Err::Failure(e)) => assert_eq!(e.input, r#"\uzzzz""#),
+            res => panic!("{res:?}"),
+        }
+    }
+}
@@ -1,7 +1,7 @@
-use nom::{bytes::complete::tag, combinator::map, IResult};
+use nom::{bytes::complete::tag, combinator::map};
 
 use crate::{
-    parser::Parser,
+    parser::{IResult, Parser},
     visitor::{writer::Writer, Visitable, VisitableMut, Visitor, VisitorMut},
 };
 
@@ -37,6 +37,7 @@ macro_rules! define_symbol {
 }
 
 define_symbol! {
+    DotDot => "..",
     Dot => ".",
     Semi => ";",
     Comma => ",",
@@ -56,6 +57,38 @@ define_symbol! {
     Exclamation => "!",
     Question => "?",
     Underscore => "_",
+    At => "@",
+    // Compound assignments
+    RAngleRAngleRAngleEqual => ">>>=",
+    RAngleRAngleEqual => ">>=",
+    LAngleLAngleEqual => "<<=",
+    StarStarEqual => "**=",
+    PlusEqual => "+=",
+    MinusEqual => "-=",
+    StarEqual => "*=",
+    SlashEqual => "/=",
+    PercentEqual => "%=",
+    BackslashEqual => "\\=",
+    AmpersandEqual => "&=",
+    PipeEqual => "|=",
+    CaretEqual => "^=",
+    // Compound operators
+    MinusRAngle => "->",
+    EqualRAngle => "=>",
+    PlusPlus => "++",
+    MinusMinus => "--",
+    StarStar => "**",
+    LAngleLAngle => "<<",
+    RAngleRAngleRAngle => ">>>",
+    RAngleRAngle => ">>",
+    LAngleEqual => "<=",
+    RAngleEqual => ">=",
+    EqualEqualEqual => "===",
+    EqualEqual => "==",
+    ExclamationEqualEqual => "!==",
+    ExclamationEqual => "!=",
+    AmpersandAmpersand => "&&",
+    PipePipe => "||",
     // Quotes
     Backtick => "`",
     SQuote => "'",
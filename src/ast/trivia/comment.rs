@@ -6,7 +6,7 @@ use nom::{
 };
 
 use crate::{
-    parser::Parser,
+    parser::{IResult, Parser},
     visitor::{writer::Writer, Visitable, VisitableMut, Visitor, VisitorMut},
 };
 
@@ -17,7 +17,7 @@ pub enum Comment {
 }
 
 impl<'a> Parser<&'a str> for Comment {
-    fn parse(input: &'a str) -> nom::IResult<&'a str, Self> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
         alt((
             map(
                 tuple((tag("//"), take_until("\n"), opt(tag("\n")))),
@@ -1,4 +1,5 @@
 pub mod comment;
+#[allow(clippy::module_inception)]
 pub mod trivia;
 pub mod whitespace;
 pub mod with_trivia;
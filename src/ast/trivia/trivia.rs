@@ -1,7 +1,7 @@
 use nom::{branch::alt, combinator::map};
 
 use crate::{
-    parser::Parser,
+    parser::{IResult, Parser},
     visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
 };
 
@@ -13,8 +13,19 @@ pub enum Trivia {
     Comment(Comment),
 }
 
+impl Trivia {
+    /// Whether the trivia ends a line, a single line comment always doing so.
+    pub fn has_newline(&self) -> bool {
+        match self {
+            Self::Whitespace(whitespace) => whitespace.0.contains('\n'),
+            Self::Comment(Comment::SingleLine(..)) => true,
+            Self::Comment(Comment::MultiLine(content)) => content.contains('\n'),
+        }
+    }
+}
+
 impl<'a> Parser<&'a str> for Trivia {
-    fn parse(input: &'a str) -> nom::IResult<&'a str, Self> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
         alt((
             map(Whitespace::parse, Self::Whitespace),
             map(Comment::parse, Self::Comment),
@@ -1,7 +1,7 @@
 use nom::combinator::map;
 
 use crate::{
-    parser::Parser,
+    parser::{IResult, Parser},
     visitor::{writer::Writer, Visitable, VisitableMut, Visitor, VisitorMut},
 };
 
@@ -9,7 +9,7 @@ use crate::{
 pub struct Whitespace(pub String);
 
 impl<'a> Parser<&'a str> for Whitespace {
-    fn parse(input: &'a str) -> nom::IResult<&'a str, Self> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
         map(nom::character::complete::multispace1, |ws: &'a str| {
             Self(ws.into())
         })(input)
@@ -2,7 +2,7 @@ use nom::{combinator::map, sequence::tuple};
 
 use crate::{
     ast::utils::repeat::Repeat0,
-    parser::Parser,
+    parser::{IResult, Parser},
     visitor::{Visitable, VisitableMut, Visitor, VisitorMut},
 };
 
@@ -12,7 +12,7 @@ use super::trivia::Trivia;
 pub struct WithTrivia<T>(pub Vec<Trivia>, pub T);
 
 impl<'a, T: Parser<&'a str>> Parser<&'a str> for WithTrivia<T> {
-    fn parse(input: &'a str) -> nom::IResult<&'a str, Self> {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
         map(
             tuple((Repeat0::<Trivia>::parse, T::parse)),
             |(trivia, item)| Self(trivia.into(), item),
@@ -33,3 +33,18 @@ impl<V: VisitorMut, T: VisitableMut<V>> VisitableMut<V> for WithTrivia<T> {
         self.1.accept_mut(visitor);
     }
 }
+
+/// Parses leading trivia followed by `parser`, for nodes that are not parsed
+/// through a plain `T::parse` (precedence levels, for instance).
+pub fn with_trivia<'a, O, P>(
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, WithTrivia<O>>
+where
+    P: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    move |input| {
+        let (input, trivia) = Repeat0::<Trivia>::parse(input)?;
+        let (input, item) = parser(input)?;
+        Ok((input, WithTrivia(trivia.into(), item)))
+    }
+}
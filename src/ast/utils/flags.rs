@@ -1,13 +1,14 @@
-use std::marker::PhantomData;
+use std::{fmt::Debug, marker::PhantomData};
 
-use nom::{
-    error::{Error, ErrorKind},
-    IResult,
-};
+use nom::error::{ErrorKind, ParseError as _};
 
 use crate::visitor::{Visitable, VisitableMut, Visitor, VisitorMut};
 
-use crate::parser::Parser;
+use crate::{
+    ast::statements::GlobalFlag,
+    error::{ParseError, ParseErrorKind},
+    parser::{IResult, Parser},
+};
 
 // Type-level list definitions
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -18,9 +19,12 @@ pub struct Cons<Head: Flag, Tail>(PhantomData<Head>, PhantomData<Tail>);
 
 pub trait Flag {
     const FLAG: bool;
+    /// Tells flags apart when looking one up in a list, unique per flag.
+    const ID: u8;
 }
 impl Flag for Nil {
     const FLAG: bool = false;
+    const ID: u8 = u8::MAX;
 }
 
 // Define the WithFlag trait
@@ -36,21 +40,87 @@ impl<T: Flag> WithFlag<T> for Nil {
     const HAS: bool = false;
 }
 
-// If the head is not the flag, delegate to the tail
+// The list has the flag if its head is the flag, otherwise delegate to the tail
 impl<Head, Tail, T: Flag> WithFlag<T> for Cons<Head, Tail>
 where
-    Tail: WithFlag<T>,      // Delegate to
-    Head: IsSame<T> + Flag, // Ensure U is not T
+    Tail: WithFlag<T>,
+    Head: Flag,
+{
+    const HAS: bool = Head::ID == T::ID || Tail::HAS;
+}
+
+/// A flag list describing the context a statement is parsed in, and how it
+/// changes when entering a nested construct.
+///
+/// Nodes are generic over the scope they are parsed in, and the statements
+/// of a block, of a loop body or of a function body take the matching
+/// associated scope, e.g. `Block<F>` holds `Statements<F::Block>`.
+///
+/// Entering a construct twice must give the same flags as entering it once,
+/// which keeps the set of statement types finite.
+pub trait Scope:
+    WithFlag<GlobalFlag>
+    + WithFlag<LoopFlag>
+    + WithFlag<FunctionFlag>
+    + WithFlag<ClassFlag>
+    + Debug
+    + Clone
+    + Eq
 {
-    const HAS: bool = Head::SAME || Tail::HAS;
+    /// Flags in effect inside a `{ ... }` block, which never include
+    /// [`GlobalFlag`].
+    type Block: Scope<Block = Self::Block, Loop = Self::Loop, Function = Self::Function>;
+    /// Flags in effect in the body of a loop, which include [`LoopFlag`].
+    type Loop: Scope<Block = Self::Loop, Loop = Self::Loop, Function = Self::Function>;
+    /// Flags in effect in the body of a function, which include
+    /// [`FunctionFlag`] but not the [`LoopFlag`] of an enclosing loop.
+    type Function: Scope<Block = Self::Function, Function = Self::Function>;
 }
 
-trait IsSame<T> {
-    const SAME: bool = false;
+impl Scope for Nil {
+    type Block = Nil;
+    type Loop = Cons<LoopFlag, Nil>;
+    type Function = Cons<FunctionFlag, Nil>;
+}
+
+/// Set inside the body of a loop, where `break` and `continue` are allowed.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LoopFlag;
+impl Flag for LoopFlag {
+    const FLAG: bool = true;
+    const ID: u8 = 1;
+}
+impl<T: Scope> Scope for Cons<LoopFlag, T> {
+    type Block = Cons<LoopFlag, T::Block>;
+    type Loop = Cons<LoopFlag, T::Block>;
+    type Function = T::Function;
+}
+
+/// Set inside the body of a function, where `return` is allowed.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FunctionFlag;
+impl Flag for FunctionFlag {
+    const FLAG: bool = true;
+    const ID: u8 = 2;
+}
+impl<T: Scope> Scope for Cons<FunctionFlag, T> {
+    type Block = Cons<FunctionFlag, T::Block>;
+    type Loop = Cons<LoopFlag, Cons<FunctionFlag, T::Block>>;
+    type Function = T::Function;
 }
 
-impl<T> IsSame<T> for T {
-    const SAME: bool = true;
+/// Set inside the non-static members of a class, where `this` and `super`
+/// are allowed. Functions nested in a member keep it.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ClassFlag;
+impl Flag for ClassFlag {
+    const FLAG: bool = true;
+    const ID: u8 = 3;
+}
+impl<T: Scope> Scope for Cons<ClassFlag, T> {
+    type Block = Cons<ClassFlag, T::Block>;
+    type Loop = Cons<ClassFlag, T::Loop>;
+    type Function = Cons<ClassFlag, T::Function>;
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -69,6 +139,23 @@ where
     }
 }
 
+impl<T, S> HasFlag<T, S>
+where
+    S: WithFlag<T>,
+    T: Flag,
+{
+    /// Checks the flag for a construct found at `input`, failing with `kind`
+    /// when it is missing. Unlike parsing the flag, the failure stops the
+    /// parse so that it is reported instead of a generic error.
+    pub fn require<I>(input: I, kind: ParseErrorKind) -> Result<Self, nom::Err<ParseError<I>>> {
+        if <S as WithFlag<T>>::HAS && T::FLAG {
+            Ok(Self::default())
+        } else {
+            Err(nom::Err::Failure(ParseError::new(input, kind)))
+        }
+    }
+}
+
 impl<I, S, T> Parser<I> for HasFlag<T, S>
 where
     S: WithFlag<T>,
@@ -80,7 +167,10 @@ where
         if <S as WithFlag<T>>::HAS && T::FLAG {
             Ok((input, Self::default()))
         } else {
-            Err(nom::Err::Error(Error::new(input, ErrorKind::Alt)))
+            Err(nom::Err::Error(ParseError::from_error_kind(
+                input,
+                ErrorKind::Alt,
+            )))
         }
     }
 }
@@ -112,7 +202,10 @@ where
         if !<S as WithFlag<T>>::HAS || !T::FLAG {
             Ok((input, Self::default()))
         } else {
-            Err(nom::Err::Error(Error::new(input, ErrorKind::Alt)))
+            Err(nom::Err::Error(ParseError::from_error_kind(
+                input,
+                ErrorKind::Alt,
+            )))
         }
     }
 }
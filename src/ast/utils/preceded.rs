@@ -1,8 +1,8 @@
-use nom::{combinator::map, sequence::tuple, IResult};
+use nom::{combinator::map, sequence::tuple};
 
 use crate::visitor::{Visitable, VisitableMut, Visitor, VisitorMut};
 
-use crate::parser::Parser;
+use crate::parser::{IResult, Parser};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Preceded<Term, T>(pub Term, pub T);
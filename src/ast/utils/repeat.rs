@@ -1,11 +1,11 @@
-use nom::{
-    error::{Error, ErrorKind},
-    IResult,
-};
+use nom::error::{ErrorKind, ParseError as _};
 
 use crate::visitor::{Visitable, VisitableMut, Visitor, VisitorMut};
 
-use crate::parser::Parser;
+use crate::{
+    error::ParseError,
+    parser::{IResult, Parser},
+};
 
 pub type Repeat0<T> = Repeat<T, 0, { usize::MAX }>;
 pub type Repeat1<T> = Repeat<T, 1, { usize::MAX }>;
@@ -31,11 +31,15 @@ where
                     items.push(item);
                     input = rest;
                 }
-                Err(_) => break, // Stop on first error
+                Err(nom::Err::Error(_)) => break, // Stop on first error
+                Err(e) => return Err(e),
             }
         }
         if items.len() < MIN {
-            Err(nom::Err::Error(Error::new(input, ErrorKind::Many0)))
+            Err(nom::Err::Error(ParseError::from_error_kind(
+                input,
+                ErrorKind::Many0,
+            )))
         } else {
             Ok((input, Repeat(items)))
         }
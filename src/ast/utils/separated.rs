@@ -1,52 +1,116 @@
-use nom::{
-    error::{Error, ErrorKind},
-    IResult,
-};
+use nom::error::{ErrorKind, ParseError as _};
 
 use crate::visitor::{Visitable, VisitableMut, Visitor, VisitorMut};
 
-use crate::parser::Parser;
+use crate::{
+    error::ParseError,
+    parser::{IResult, Parser},
+};
 
 pub type Separated0<T, S> = Separated<T, S, 0, { usize::MAX }>;
 pub type Separated1<T, S> = Separated<T, S, 1, { usize::MAX }>;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Separated<T, S, const MIN: usize, const MAX: usize>(pub Vec<T>, pub Vec<S>);
-impl<I, T, S, const MIN: usize, const MAX: usize> Parser<I> for Separated<T, S, MIN, MAX>
-where
-    I: Clone,
-    T: Parser<I>,
-    S: Parser<I>,
-{
-    fn parse(mut input: I) -> IResult<I, Self> {
+impl<T, S, const MIN: usize, const MAX: usize> Separated<T, S, MIN, MAX> {
+    /// Parses the list with explicit item and separator parsers. A separator
+    /// must be followed by an item, so the list stops before a trailing
+    /// separator.
+    pub fn parse_with<I, PT, PS>(input: I, item: PT, sep: PS) -> IResult<I, Self>
+    where
+        I: Clone,
+        PT: FnMut(I) -> IResult<I, T>,
+        PS: FnMut(I) -> IResult<I, S>,
+    {
+        Self::parse_list(input, item, sep, false)
+    }
+
+    /// Same as [`Self::parse_with`], but keeps a trailing separator, for the
+    /// lists written as `[1, 2,]`.
+    pub fn parse_trailing_with<I, PT, PS>(input: I, item: PT, sep: PS) -> IResult<I, Self>
+    where
+        I: Clone,
+        PT: FnMut(I) -> IResult<I, T>,
+        PS: FnMut(I) -> IResult<I, S>,
+    {
+        Self::parse_list(input, item, sep, true)
+    }
+
+    /// Parses the list with the item and separator parsers, keeping a
+    /// trailing separator.
+    pub fn parse_trailing<I>(input: I) -> IResult<I, Self>
+    where
+        I: Clone,
+        T: Parser<I>,
+        S: Parser<I>,
+    {
+        Self::parse_trailing_with(input, T::parse, S::parse)
+    }
+
+    fn parse_list<I, PT, PS>(
+        mut input: I,
+        mut item: PT,
+        mut sep: PS,
+        trailing: bool,
+    ) -> IResult<I, Self>
+    where
+        I: Clone,
+        PT: FnMut(I) -> IResult<I, T>,
+        PS: FnMut(I) -> IResult<I, S>,
+    {
         let mut items = Vec::new();
         let mut separators = Vec::new();
+        // Input before the last separator, to give it back if no item follows
+        let mut before_sep = None;
         for i in 0..MAX {
-            match T::parse(input.clone()) {
+            match item(input.clone()) {
                 Ok((rest, item)) => {
                     items.push(item);
                     input = rest;
                 }
-                Err(_) => break, // Stop on first error
+                Err(nom::Err::Error(_)) => {
+                    if let (false, Some(before)) = (trailing, before_sep) {
+                        separators.pop();
+                        input = before;
+                    }
+                    break; // Stop on first error
+                }
+                Err(e) => return Err(e),
             }
             if i == MAX - 1 {
                 break;
             }
-            match S::parse(input.clone()) {
+            match sep(input.clone()) {
                 Ok((rest, sep)) => {
                     separators.push(sep);
+                    before_sep = Some(input);
                     input = rest;
                 }
-                Err(_) => break, // Stop on first error
+                Err(nom::Err::Error(_)) => break, // Stop on first error
+                Err(e) => return Err(e),
             }
         }
         if items.len() < MIN {
-            Err(nom::Err::Error(Error::new(input, ErrorKind::Many0)))
+            Err(nom::Err::Error(ParseError::from_error_kind(
+                input,
+                ErrorKind::Many0,
+            )))
         } else {
             Ok((input, Separated(items, separators)))
         }
     }
 }
 
+impl<I, T, S, const MIN: usize, const MAX: usize> Parser<I> for Separated<T, S, MIN, MAX>
+where
+    I: Clone,
+    T: Parser<I>,
+    S: Parser<I>,
+{
+    fn parse(input: I) -> IResult<I, Self> {
+        Self::parse_with(input, T::parse, S::parse)
+    }
+}
+
 impl<V, T, S, const MIN: usize, const MAX: usize> Visitable<V> for Separated<T, S, MIN, MAX>
 where
     V: Visitor,
@@ -54,11 +118,13 @@ where
     S: Visitable<V>,
 {
     default fn accept(&self, visitor: &mut V) {
-        for (item, sep) in self.0.iter().zip(&self.1) {
+        // A trailing separator has no item after it
+        for (i, item) in self.0.iter().enumerate() {
             visitor.visit(item);
-            visitor.visit(sep);
+            if let Some(sep) = self.1.get(i) {
+                visitor.visit(sep);
+            }
         }
-        visitor.visit(self.0.last().unwrap());
     }
 }
 
@@ -69,10 +135,53 @@ where
     S: VisitableMut<V>,
 {
     default fn accept_mut(&mut self, visitor: &mut V) {
-        for i in 0..(self.0.len() - 1) {
-            visitor.visit_mut(&mut self.0[i]);
-            visitor.visit_mut(&mut self.1[i]);
+        for (i, item) in self.0.iter_mut().enumerate() {
+            visitor.visit_mut(item);
+            if let Some(sep) = self.1.get_mut(i) {
+                visitor.visit_mut(sep);
+            }
         }
-        visitor.visit_mut(self.0.last_mut().unwrap());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Separated, Separated0, Separated1};
+    use crate::{
+        ast::terminal::{number::IntegerLiteral, symbol::Comma},
+        parser::Parser,
+    };
+
+    type List = Separated0<IntegerLiteral, Comma>;
+
+    fn counts<const MIN: usize, const MAX: usize>(
+        list: &Separated<IntegerLiteral, Comma, MIN, MAX>,
+    ) -> (usize, usize) {
+        (list.0.len(), list.1.len())
+    }
+
+    #[test]
+    fn items_and_separators() {
+        let (rest, list) = List::parse("1,2,3").unwrap();
+        assert_eq!((rest, counts(&list)), ("", (3, 2)));
+        let (rest, list) = List::parse("").unwrap();
+        assert_eq!((rest, counts(&list)), ("", (0, 0)));
+        assert!(Separated1::<IntegerLiteral, Comma>::parse("").is_err());
+    }
+
+    #[test]
+    fn trailing_separator_is_opt_in() {
+        let (rest, list) = List::parse("1,2,").unwrap();
+        assert_eq!((rest, counts(&list)), (",", (2, 1)));
+        let (rest, list) = List::parse_trailing("1,2,").unwrap();
+        assert_eq!((rest, counts(&list)), ("", (2, 2)));
+        let (rest, list) = List::parse(",").unwrap();
+        assert_eq!((rest, counts(&list)), (",", (0, 0)));
+    }
+
+    #[test]
+    fn maximum() {
+        let (rest, list) = Separated::<IntegerLiteral, Comma, 0, 2>::parse("1,2,3").unwrap();
+        assert_eq!((rest, counts(&list)), (",3", (2, 1)));
     }
 }
@@ -0,0 +1,58 @@
+use nom::error::{ErrorKind, FromExternalError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError<I> {
+    pub input: I,
+    pub kind: ParseErrorKind,
+}
+
+impl<I> ParseError<I> {
+    pub fn new(input: I, kind: ParseErrorKind) -> Self {
+        Self { input, kind }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// A generic failure reported by a nom combinator.
+    Nom(ErrorKind),
+    /// An integer literal that does not fit in 64 bits.
+    IntegerOverflow,
+    /// A `0x` or `0b` prefix that is not followed by any digit.
+    MissingDigits,
+    /// A string literal is missing its closing quote.
+    UnterminatedString,
+    /// A `\u` escape in a string literal is not a valid code point.
+    InvalidEscape,
+    /// A v1 `@` reference marker used with a later language version.
+    ReferenceNotSupported,
+    /// An assignment to something other than a variable, a member or an
+    /// element, as in `a + b = c`.
+    InvalidAssignmentTarget,
+    /// A `break` outside of a loop.
+    BreakOutsideLoop,
+    /// A `continue` outside of a loop.
+    ContinueOutsideLoop,
+    /// A `return` outside of a function.
+    ReturnOutsideFunction,
+    /// A `this` outside of the members of a class.
+    ThisOutsideClass,
+    /// A `super` outside of the members of a class.
+    SuperOutsideClass,
+}
+
+impl<I> nom::error::ParseError<I> for ParseError<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        Self::new(input, ParseErrorKind::Nom(kind))
+    }
+
+    fn append(_: I, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<I, E> FromExternalError<I, E> for ParseError<I> {
+    fn from_external_error(input: I, kind: ErrorKind, _: E) -> Self {
+        Self::new(input, ParseErrorKind::Nom(kind))
+    }
+}
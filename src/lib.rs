@@ -1,7 +1,10 @@
-#![feature(associated_const_equality)]
 #![feature(min_specialization)]
-#![feature(associated_type_defaults)]
 
 pub mod ast;
+pub mod error;
 pub mod parser;
+pub mod resolver;
 pub mod visitor;
+
+#[cfg(test)]
+mod test_utils;
@@ -1,6 +1,10 @@
-use std::marker::PhantomData;
+use std::{cell::Cell, marker::PhantomData};
 
-use nom::{combinator::opt, multi::many0, sequence::tuple, IResult, InputLength};
+use nom::{combinator::opt, multi::many0, sequence::tuple, InputLength};
+
+use crate::error::ParseError;
+
+pub type IResult<I, O> = nom::IResult<I, O, ParseError<I>>;
 
 pub trait Parser<I>: Sized {
     fn parse(input: I) -> IResult<I, Self>;
@@ -92,3 +96,39 @@ where
         T::parse(input).map(|(rest, item)| (rest, Box::new(item)))
     }
 }
+
+/// LeekScript language version, which decides the reserved words and the
+/// legacy syntax accepted by the parser.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Version {
+    V1,
+    V2,
+    V3,
+    #[default]
+    V4,
+}
+
+thread_local! {
+    static VERSION: Cell<Version> = Cell::new(Version::default());
+}
+
+/// Language version used by parsers running on the current thread, the
+/// latest one unless parsing through
+/// [`File::parse_with_version`](crate::ast::structure::file::File::parse_with_version).
+pub(crate) fn version() -> Version {
+    VERSION.with(Cell::get)
+}
+
+/// Runs `f` with `version` as the language version, restoring the previous
+/// version afterwards.
+pub(crate) fn with_version<R>(version: Version, f: impl FnOnce() -> R) -> R {
+    struct Restore(Version);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            VERSION.with(|v| v.set(self.0));
+        }
+    }
+
+    let _restore = Restore(VERSION.with(|v| v.replace(version)));
+    f()
+}
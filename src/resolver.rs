@@ -0,0 +1,311 @@
+//! Loading the files pulled in by `include` statements.
+
+use std::{collections::HashMap, fs, ops::Range, path::PathBuf};
+
+use crate::{
+    ast::{
+        statements::{include_statement::IncludeStatement, Statements},
+        structure::file::File,
+    },
+    error::ParseErrorKind,
+    parser::Version,
+    visitor::{writer::Writer, Visitor},
+};
+
+/// Provides the source of included files.
+pub trait Loader {
+    /// Returns the source of the file `path` as written in the `include`, or
+    /// `None` if there is no such file.
+    fn load(&mut self, path: &str) -> Option<String>;
+}
+
+/// In-memory sources keyed by include path.
+impl Loader for HashMap<String, String> {
+    fn load(&mut self, path: &str) -> Option<String> {
+        self.get(path).cloned()
+    }
+}
+
+/// Reads included files relative to a root directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSystemLoader(pub PathBuf);
+impl Loader for FileSystemLoader {
+    fn load(&mut self, path: &str) -> Option<String> {
+        fs::read_to_string(self.0.join(path)).ok()
+    }
+}
+
+/// A parsed file and the path it was loaded from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedFile {
+    pub path: String,
+    pub file: File,
+}
+
+/// A problem with an `include` call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncludeDiagnostic {
+    /// Path of the file containing the `include` call.
+    pub file: String,
+    /// The `include` call itself.
+    pub include: IncludeStatement,
+    /// Byte range of the `include` call in the source of `file`, including
+    /// its semicolon if it has one.
+    pub span: Range<usize>,
+    pub kind: IncludeErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IncludeErrorKind {
+    /// The loader has no file at the included path.
+    Missing,
+    /// The included file is already being included, the chain of paths
+    /// going from it back to itself.
+    Cycle(Vec<String>),
+    /// The included file does not parse, failing at `offset` bytes into its
+    /// source.
+    Parse { kind: ParseErrorKind, offset: usize },
+}
+
+/// Every file reachable from a root file, with the problems found on the way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resolution {
+    /// The root file first, then included files in the order they are found.
+    pub files: Vec<ResolvedFile>,
+    pub diagnostics: Vec<IncludeDiagnostic>,
+}
+
+/// Follows `include` statements from a root file, loading each included file
+/// once through a [`Loader`] and parsing it for the given language version.
+#[derive(Debug, Clone)]
+pub struct Resolver<L: Loader>(pub L, pub Version);
+impl<L: Loader> Resolver<L> {
+    pub fn resolve(&mut self, path: &str, root: File) -> Resolution {
+        let mut resolution = Resolution {
+            files: Vec::new(),
+            diagnostics: Vec::new(),
+        };
+        let mut stack = Vec::new();
+        self.visit_file(path.to_string(), root, &mut stack, &mut resolution);
+        resolution
+    }
+
+    fn visit_file(
+        &mut self,
+        path: String,
+        file: File,
+        stack: &mut Vec<String>,
+        resolution: &mut Resolution,
+    ) {
+        // Files keep all of their source, so writing the statements back
+        // gives the position of each include.
+        let mut includes = Vec::new();
+        let mut writer = Writer::default();
+        for statement in &file.statements {
+            writer.visit(&statement.0);
+            let start = writer.0.len();
+            writer.visit(&statement.1);
+            if let Statements::Include(_, include) = &statement.1 {
+                includes.push((start..writer.0.len(), include.clone()));
+            }
+        }
+        resolution.files.push(ResolvedFile {
+            path: path.clone(),
+            file,
+        });
+
+        stack.push(path.clone());
+        for (span, include) in includes {
+            let target = include.path().to_string();
+            let diagnostic = |kind| IncludeDiagnostic {
+                file: path.clone(),
+                include: include.clone(),
+                span: span.clone(),
+                kind,
+            };
+
+            if let Some(start) = stack.iter().position(|p| *p == target) {
+                let mut cycle = stack[start..].to_vec();
+                cycle.push(target);
+                resolution
+                    .diagnostics
+                    .push(diagnostic(IncludeErrorKind::Cycle(cycle)));
+                continue;
+            }
+            if resolution.files.iter().any(|f| f.path == target) {
+                continue;
+            }
+            let Some(source) = self.0.load(&target) else {
+                resolution
+                    .diagnostics
+                    .push(diagnostic(IncludeErrorKind::Missing));
+                continue;
+            };
+            match File::parse_with_version(&source, self.1) {
+                Ok((_, included)) => self.visit_file(target, included, stack, resolution),
+                Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+                    resolution
+                        .diagnostics
+                        .push(diagnostic(IncludeErrorKind::Parse {
+                            kind: e.kind,
+                            offset: source.len() - e.input.len(),
+                        }))
+                }
+                Err(nom::Err::Incomplete(_)) => unreachable!("parsers are complete"),
+            }
+        }
+        stack.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{IncludeErrorKind, Resolution, Resolver};
+    use crate::{
+        ast::structure::file::File,
+        error::ParseErrorKind,
+        parser::{Parser, Version},
+        test_utils::round_trip,
+    };
+
+    fn resolve(root: &str, files: &[(&str, &str)]) -> Resolution {
+        let files = files
+            .iter()
+            .map(|(path, source)| (path.to_string(), source.to_string()))
+            .collect::<HashMap<_, _>>();
+        Resolver(files, Version::default()).resolve("main", round_trip::<File>(root))
+    }
+
+    fn paths(resolution: &Resolution) -> Vec<&str> {
+        resolution
+            .files
+            .iter()
+            .map(|file| file.path.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn loads_included_files() {
+        let resolution = resolve(
+            "include(\"a\");\nvar x = 1",
+            &[("a", "include('b')\nvar y = 2"), ("b", "var z = 3")],
+        );
+        assert_eq!(paths(&resolution), ["main", "a", "b"]);
+        assert!(resolution.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn missing_files() {
+        let root = "var x = 1\n/* first */ include(\"nope\");\n";
+        let resolution = resolve(root, &[]);
+        assert_eq!(paths(&resolution), ["main"]);
+        let [diagnostic] = &resolution.diagnostics[..] else {
+            panic!("{:?}", resolution.diagnostics);
+        };
+        assert_eq!(diagnostic.file, "main");
+        assert_eq!(diagnostic.kind, IncludeErrorKind::Missing);
+        assert_eq!(diagnostic.include.path(), "nope");
+        assert_eq!(&root[diagnostic.span.clone()], "include(\"nope\");");
+    }
+
+    #[test]
+    fn direct_cycles() {
+        let resolution = resolve("include(\"a\")", &[("a", "include(\"a\")")]);
+        assert_eq!(paths(&resolution), ["main", "a"]);
+        let [diagnostic] = &resolution.diagnostics[..] else {
+            panic!("{:?}", resolution.diagnostics);
+        };
+        assert_eq!(diagnostic.file, "a");
+        assert_eq!(
+            diagnostic.kind,
+            IncludeErrorKind::Cycle(vec!["a".into(), "a".into()])
+        );
+        assert_eq!(diagnostic.span, 0..12);
+    }
+
+    #[test]
+    fn indirect_cycles() {
+        let resolution = resolve(
+            "include(\"a\")",
+            &[
+                ("a", "include(\"b\")"),
+                ("b", "var x = 1\ninclude(\"c\")"),
+                ("c", "include(\"a\")"),
+            ],
+        );
+        assert_eq!(paths(&resolution), ["main", "a", "b", "c"]);
+        let [diagnostic] = &resolution.diagnostics[..] else {
+            panic!("{:?}", resolution.diagnostics);
+        };
+        assert_eq!(diagnostic.file, "c");
+        assert_eq!(
+            diagnostic.kind,
+            IncludeErrorKind::Cycle(vec!["a".into(), "b".into(), "c".into(), "a".into()])
+        );
+        let resolution = resolve("include(\"main\")", &[]);
+        assert_eq!(
+            resolution.diagnostics[0].kind,
+            IncludeErrorKind::Cycle(vec!["main".into(), "main".into()])
+        );
+    }
+
+    #[test]
+    fn diamonds_load_once() {
+        let resolution = resolve(
+            "include(\"left\")\ninclude(\"right\")",
+            &[
+                ("left", "include(\"base\")"),
+                ("right", "include(\"base\")"),
+                ("base", "global g = 1"),
+            ],
+        );
+        assert_eq!(paths(&resolution), ["main", "left", "base", "right"]);
+        assert!(resolution.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn parse_errors() {
+        let root = "include(\"ok\")\n    include(\"bad\")";
+        let resolution = resolve(root, &[("ok", "var a = 1"), ("bad", "var b = 1\nwhile (")]);
+        assert_eq!(paths(&resolution), ["main", "ok"]);
+        let [diagnostic] = &resolution.diagnostics[..] else {
+            panic!("{:?}", resolution.diagnostics);
+        };
+        assert_eq!(diagnostic.file, "main");
+        assert_eq!(&root[diagnostic.span.clone()], "include(\"bad\")");
+        let IncludeErrorKind::Parse { offset, .. } = diagnostic.kind else {
+            panic!("{diagnostic:?}");
+        };
+        assert!(offset >= "var b = 1\n".len(), "{offset}");
+        let resolution = resolve("include(\"bad\")", &[("bad", "break")]);
+        assert_eq!(
+            resolution.diagnostics[0].kind,
+            IncludeErrorKind::Parse {
+                kind: ParseErrorKind::BreakOutsideLoop,
+                offset: 0
+            }
+        );
+    }
+
+    #[test]
+    fn included_files_use_the_resolver_version() {
+        let files: HashMap<String, String> = [("a".to_string(), "var @x = 1".to_string())].into();
+        let root = || File::parse("include(\"a\")").unwrap().1;
+        let resolution = Resolver(files.clone(), Version::V1).resolve("main", root());
+        assert!(
+            resolution.diagnostics.is_empty(),
+            "{:?}",
+            resolution.diagnostics
+        );
+        let resolution = Resolver(files, Version::V4).resolve("main", root());
+        assert!(matches!(
+            resolution.diagnostics[0].kind,
+            IncludeErrorKind::Parse {
+                kind: ParseErrorKind::ReferenceNotSupported,
+                ..
+            }
+        ));
+    }
+}
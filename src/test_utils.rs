@@ -0,0 +1,102 @@
+//! Helpers shared by the parser tests.
+
+use crate::{
+    ast::{expressions, utils::flags::Nil},
+    error::ParseErrorKind,
+    parser::Parser,
+    visitor::{writer::Writer, Visitable, Visitor},
+};
+
+/// Expressions as parsed at the top level, outside of functions and classes.
+pub type Expression = expressions::Expression<Nil>;
+
+/// Writes `node` back to source.
+pub fn write<T: Visitable<Writer>>(node: &T) -> String {
+    let mut writer = Writer::default();
+    writer.visit(node);
+    writer.0
+}
+
+/// Parses the whole of `src` as a `T` and checks that it is written back
+/// unchanged.
+pub fn round_trip<T>(src: &str) -> T
+where
+    T: for<'a> Parser<&'a str> + Visitable<Writer>,
+{
+    let (rest, node) = T::parse(src).unwrap_or_else(|e| panic!("{src:?} does not parse: {e:?}"));
+    assert_eq!(rest, "", "{src:?} is not parsed completely");
+    assert_eq!(write(&node), src);
+    node
+}
+
+/// Checks that `src` is not parsed completely as a `T`.
+pub fn rejects<T>(src: &str)
+where
+    T: for<'a> Parser<&'a str> + std::fmt::Debug,
+{
+    if let Ok((rest, node)) = T::parse(src) {
+        assert!(!rest.is_empty(), "{src:?} parses as {node:#?}");
+    }
+}
+
+/// The kind of the error returned when parsing `src` as a `T`.
+pub fn error_kind<T>(src: &str) -> ParseErrorKind
+where
+    T: for<'a> Parser<&'a str> + std::fmt::Debug,
+{
+    match T::parse(src) {
+        Ok(res) => panic!("{src:?} parses as {res:#?}"),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => e.kind,
+        Err(nom::Err::Incomplete(_)) => unreachable!("parsers are complete"),
+    }
+}
+
+/// Parses `src` as an expression and writes it back with every operation
+/// in parentheses, to check how operators group.
+pub fn grouped(src: &str) -> String {
+    group(&round_trip::<Expression>(src))
+}
+
+fn group(expr: &Expression) -> String {
+    match expr {
+        Expression::Binary(node) => format!(
+            "({} {} {})",
+            group(&node.0),
+            write(&node.1 .1),
+            group(&node.2 .1)
+        ),
+        Expression::Prefix(node) => {
+            format!("({} {})", write(&node.0).trim(), group(&node.1 .1))
+        }
+        Expression::Postfix(node) => format!("({}{})", group(&node.0), write(&node.1 .1)),
+        Expression::Ternary(node) => format!(
+            "({} ? {} : {})",
+            group(&node.0),
+            group(&node.2 .1),
+            group(&node.4 .1)
+        ),
+        Expression::Assignment(node) => format!(
+            "({} {} {})",
+            group(&node.0),
+            write(&node.1 .1),
+            group(&node.2 .1)
+        ),
+        Expression::Cast(node) => {
+            format!("({} as {})", group(&node.0), write(&node.2 .1).trim())
+        }
+        Expression::Call(node) => {
+            let arguments: Vec<_> = node.1 .1 .1 .0.iter().map(|a| group(&a.1)).collect();
+            format!("{}({})", group(&node.0), arguments.join(", "))
+        }
+        Expression::New(node) => {
+            let arguments = node.2.as_ref().map_or(String::new(), |a| {
+                let arguments: Vec<_> = a.1 .1 .0.iter().map(|a| group(&a.1)).collect();
+                format!("({})", arguments.join(", "))
+            });
+            format!("(new {}{})", group(&node.1 .1), arguments)
+        }
+        Expression::Member(node) => format!("{}.{}", group(&node.0), node.2 .1 .0),
+        Expression::Index(node) => format!("{}[{}]", group(&node.0), group(&node.2 .1)),
+        _ => write(expr),
+    }
+}
@@ -28,7 +28,7 @@ pub trait Visitable<V: Visitor + ?Sized> {
 }
 
 /// The VisitableMut trait for mutable visitors.
-pub trait VisitableMut<V: VisitorMut + ?Sized> {
+pub trait VisitableMut<V: VisitorMut> {
     fn accept_mut(&mut self, visitor: &mut V);
 }
 
@@ -178,3 +178,23 @@ where
 {
     default fn accept_mut(&mut self, _visitor: &mut V) {}
 }
+
+impl<V, T> Visitable<V> for Box<T>
+where
+    V: Visitor,
+    T: Visitable<V>,
+{
+    default fn accept(&self, visitor: &mut V) {
+        visitor.visit(self.as_ref());
+    }
+}
+
+impl<V, T> VisitableMut<V> for Box<T>
+where
+    V: VisitorMut,
+    T: VisitableMut<V>,
+{
+    default fn accept_mut(&mut self, visitor: &mut V) {
+        visitor.visit_mut(self.as_mut());
+    }
+}